@@ -1,3 +1,5 @@
+use crate::etc::checked;
+use crate::etc::direction::Direction4;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 
@@ -7,9 +9,12 @@ fn read_and_parse(path: &str) -> Vec<i32> {
         .lines()
         .map(|line| {
             let (direction, number) = line.split_at(1);
-            let mut value: i32 = number.parse().unwrap();
-            if direction == "L" { value *= -1; }
-            value
+            let value: i32 = number.parse().unwrap();
+            match direction.parse().expect("Invalid direction") {
+                Direction4::Left => -value,
+                Direction4::Right => value,
+                d => panic!("Invalid dial direction: {:?}", d),
+            }
         })
         .collect()
 }
@@ -23,8 +28,9 @@ impl Safe {
         Self { value: 50 }
     }
     fn add_value(&mut self, value: i32) -> (i32, usize) {
-        let mut count = if self.value != 0 && self.value + value <= 0 {1} else {0};
-        self.value += value;
+        let next = checked::add(self.value, value, "dial position");
+        let mut count = if self.value != 0 && next <= 0 {1} else {0};
+        self.value = next;
         count += (self.value / 100).unsigned_abs() as usize;
         self.value = self.value.rem_euclid(100);
         (self.value, count)
@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 use std::str::FromStr;
@@ -62,7 +63,7 @@ impl Interval {
                 }
                 let max_x = self.end / factor;
                 for x in min_x..=max_x {
-                    results.insert(x * factor);
+                    results.insert(checked::mul(x, factor, "x * factor"));
                 }
             }
         }
@@ -91,14 +92,14 @@ fn part1(intervals: &[Interval]) -> u64 {
     for interval in intervals {
         interval.get_incorrect_numbers(2, &mut incorrect_id);
     }
-    incorrect_id.iter().sum()
+    checked::sum(incorrect_id.iter().copied(), "sum of invalid ids")
 }
 fn part2(intervals: &[Interval]) -> u64 {
     let mut incorrect_id = HashSet::new();
     for interval in intervals {
         interval.get_incorrect_numbers(10, &mut incorrect_id);
     }
-    incorrect_id.iter().sum()
+    checked::sum(incorrect_id.iter().copied(), "sum of invalid ids")
 }
 
 pub fn solve() -> SolutionPair {
@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 use std::str::FromStr;
@@ -38,7 +39,9 @@ impl BatteryBank {
         stack
             .iter()
             .take(num_len as usize)
-            .fold(0u64, |acc, &d| acc * 10 + d as u64)
+            .fold(0u64, |acc, &d| {
+                checked::add(checked::mul(acc, 10, "joltage * 10"), d as u64, "joltage + digit")
+            })
     }
 }
 
@@ -54,13 +57,13 @@ fn part1(banks: &[BatteryBank]) -> u64 {
     banks
         .iter()
         .map(|bank| bank.get_biggest_number(2))
-        .sum()
+        .fold(0, |acc, n| checked::add(acc, n, "sum of joltages"))
 }
 fn part2(banks: &[BatteryBank]) -> u64 {
     banks
         .iter()
         .map(|bank| bank.get_biggest_number(12))
-        .sum()
+        .fold(0, |acc, n| checked::add(acc, n, "sum of joltages"))
 }
 
 pub fn solve() -> SolutionPair {
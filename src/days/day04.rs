@@ -1,8 +1,12 @@
-use crate::{Solution, SolutionPair};
+use crate::{bench_fn, Solution, SolutionPair};
 use std::fs::read_to_string;
 use crate::etc::grid::Grid;
-use crate::etc::point::Point;
+use crate::etc::point::{Neighborhood, Point};
+use crate::etc::animate::Terminal;
+use crate::etc::Automaton;
 use std::collections::VecDeque;
+use std::hint::black_box;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
@@ -78,6 +82,50 @@ fn part2(grid: &mut Grid<Cell>) -> usize {
     count
 }
 
+// Times the neighbor count of `fill_rolls` with the neighbors collected into
+// a Vec, as `Point::neighbors_diagonal` used to return them, and with the
+// array it returns now.
+pub fn bench_neighbors() {
+    let grid = read_and_parse("inputs/day04.txt");
+    let is_roll = |n: Point| matches!(grid.get(n), Some(Cell::Roll(_)));
+    let with_vec = || {
+        grid.enumerate()
+            .map(|(pos, _)| black_box(pos.neighbors_diagonal().to_vec()).into_iter().filter(|&n| is_roll(n)).count())
+            .sum::<usize>()
+    };
+    let with_array = || {
+        grid.enumerate()
+            .map(|(pos, _)| black_box(pos.neighbors_diagonal()).into_iter().filter(|&n| is_roll(n)).count())
+            .sum::<usize>()
+    };
+    assert_eq!(with_vec(), with_array());
+
+    let (vec_min, vec_mean, _) = bench_fn(with_vec);
+    let (array_min, array_mean, _) = bench_fn(with_array);
+    println!("  . Neighbors (Vec):   min {:.4} ms, mean {:.4} ms", vec_min, vec_mean);
+    println!("  . Neighbors (array): min {:.4} ms, mean {:.4} ms ({:.1}x faster)", array_min, array_mean, vec_min / array_min);
+}
+
+// Plays each wave of removals: rolls that are accessible at the start of a
+// wave disappear together.
+pub fn visualize() {
+    let grid = read_and_parse("inputs/day04.txt");
+    let mut automaton = Automaton::new(grid.clone(), Neighborhood::Eight);
+    let mut frames = vec![grid.clone()];
+    let rule = |cell: &Cell, neighbors: &[&Cell]| {
+        let rolls = neighbors.iter().filter(|n| matches!(n, Cell::Roll(_))).count();
+        if matches!(cell, Cell::Roll(_)) && rolls < 4 { Cell::Empty } else { *cell }
+    };
+    while automaton.step(rule) > 0 {
+        frames.push(automaton.grid().clone());
+    }
+    let style = |cell: &Cell| match cell {
+        Cell::Empty => ('.', [90, 90, 90]),
+        Cell::Roll(_) => ('@', [230, 230, 230]),
+    };
+    Terminal::new(Duration::from_millis(150)).play(frames, style).expect("Failed to draw frames");
+}
+
 pub fn solve() -> SolutionPair {
     let mut grid = read_and_parse("inputs/day04.txt");
     fill_rolls(&mut grid);
@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 use std::str::FromStr;
@@ -78,10 +79,7 @@ fn part1(intervals: &[Interval], values: &[u64]) -> usize {
         .count()
 }
 fn part2(intervals: &[Interval]) -> usize {
-    intervals
-        .iter()
-        .map(|interval| interval.len())
-        .sum()
+    checked::sum(intervals.iter().map(|interval| interval.len()), "sum of interval lengths")
 
 }
 
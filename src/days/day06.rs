@@ -1,3 +1,5 @@
+use crate::etc::checked;
+use crate::etc::grid::{Grid, Ragged, Whitespace};
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 
@@ -33,28 +35,30 @@ fn part1(data: &str) -> u64 {
         .map(|(i, op)| {
             let mut col_result = values[0][i];
             for row in &values[1..] {
-                match op {
-                    Add => col_result += row[i],
-                    Multiply => col_result *= row[i],
-                }
+                col_result = match op {
+                    Add => checked::add(col_result, row[i], "column sum"),
+                    Multiply => checked::mul(col_result, row[i], "column product"),
+                };
             }
             col_result
         })
-        .sum()
+        .fold(0, |acc, v| checked::add(acc, v, "sum of columns"))
 }
 fn part2(data: &str) -> u64 {
-    let table: Vec<&[u8]> = data.lines().map(|line| line.as_bytes()).collect();
-    let mut indexes: Vec<usize> = (0..table[0].len())
-        .filter(|&i| table.iter().all(|row| row[i] == b' '))
+    let table = Grid::try_map_from_str(data, Whitespace::Keep, Ragged::Pad(' '), |c| c).unwrap();
+    let ops_row = table.height() - 1;
+    let mut indexes: Vec<usize> = (0..table.width())
+        .filter(|&x| table.column(x).all(|&c| c == ' '))
         .collect();
-    indexes.push(table[0].len());
+    indexes.push(table.width());
 
-    let operations: Vec<Operation> = table[table.len() - 1]
-        .split(|&b| b == b' ')
-        .filter(|op| !op.is_empty())
-        .map(|op| match op {
-            b"+" => Add,
-            b"*" => Multiply,
+    let operations: Vec<Operation> = table
+        .row(ops_row)
+        .iter()
+        .filter_map(|c| match c {
+            '+' => Some(Add),
+            '*' => Some(Multiply),
+            ' ' => None,
             _ => panic!("Unknown operation"),
         })
         .collect();
@@ -63,22 +67,23 @@ fn part2(data: &str) -> u64 {
     for (i, &end) in indexes.iter().enumerate() {
         let mut col_result = 0u64;
         for j in start..end {
-            let mut num = 0u64;
-            for row in &table[0..table.len() - 1] {
-                if row[j] != b' ' {
-                    num = num * 10 + (row[j] - b'0') as u64;
-                }
-            }
+            let num = table
+                .column(j)
+                .take(ops_row)
+                .filter_map(|c| c.to_digit(10))
+                .fold(0u64, |num, digit| {
+                    checked::add(checked::mul(num, 10, "num * 10"), digit as u64, "num + digit")
+                });
             if j == start {
                 col_result = num;
             } else {
-                match operations[i] {
-                    Add => col_result += num,
-                    Multiply => col_result *= num,
-                }
+                col_result = match operations[i] {
+                    Add => checked::add(col_result, num, "column sum"),
+                    Multiply => checked::mul(col_result, num, "column product"),
+                };
             }
         }
-        result += col_result;
+        result = checked::add(result, col_result, "sum of columns");
         start = end + 1;
     }
 
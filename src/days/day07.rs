@@ -1,8 +1,11 @@
+use crate::etc::checked;
+use crate::etc::animate::Terminal;
 use crate::etc::grid::Grid;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Cell {
     Empty,
     Start(usize),
@@ -34,7 +37,7 @@ impl Cell {
     }
     fn add_value(&mut self, v: usize) {
         match self {
-            Start(val) | Split(val) | Beam(val) => *val += v,
+            Start(val) | Split(val) | Beam(val) => *val = checked::add(*val, v, "beam timelines"),
             Empty => {}
         }
     }
@@ -45,50 +48,69 @@ fn read_and_parse(path: &str) -> Grid<Cell> {
     Grid::map_from_str(&input, Cell::from_char)
 }
 
-fn part1(grid: &mut Grid<Cell>) -> usize {
+// Moves the beams in row `y` down into row `y + 1` and returns how many
+// splitters in the row were hit.
+fn step_row(grid: &mut Grid<Cell>, y: usize) -> usize {
     let mut split_count = 0;
-    for y in 0..grid.height() - 1 {
-        for x in 0..grid.width() {
-            if !grid[(x, y)].is_split() {
-                continue;
-            }
-            let val = grid[(x, y)].get_value();
-            if val > 0 {
-                split_count += 1;
-            }
-            if grid[(x-1, y)].is_empty() {
-                grid[(x-1, y)] = Beam(val);
-            } else {
-                grid[(x-1, y)].add_value(val);
-            }
-            if grid[(x+1, y)].is_empty() {
-                grid[(x+1, y)] = Beam(val);
-            } else {
-                grid[(x+1, y)].add_value(val);
-            }
+    for x in 0..grid.width() {
+        if !grid[(x, y)].is_split() {
+            continue;
+        }
+        let val = grid[(x, y)].get_value();
+        if val > 0 {
+            split_count += 1;
+        }
+        if grid[(x-1, y)].is_empty() {
+            grid[(x-1, y)] = Beam(val);
+        } else {
+            grid[(x-1, y)].add_value(val);
         }
-        for x in 0..grid.width() {
-            let cell = &grid[(x,y)];
-            if cell.is_empty() || cell.is_split() {
-                continue;
-            }
-            let val = cell.get_value();
-            if grid[(x, y + 1)].is_empty() {
-                grid[(x, y + 1)] = Beam(val);
-            } else {
-                grid[(x, y + 1)].add_value(val);
-            }
+        if grid[(x+1, y)].is_empty() {
+            grid[(x+1, y)] = Beam(val);
+        } else {
+            grid[(x+1, y)].add_value(val);
+        }
+    }
+    for x in 0..grid.width() {
+        let cell = &grid[(x,y)];
+        if cell.is_empty() || cell.is_split() {
+            continue;
+        }
+        let val = cell.get_value();
+        if grid[(x, y + 1)].is_empty() {
+            grid[(x, y + 1)] = Beam(val);
+        } else {
+            grid[(x, y + 1)].add_value(val);
         }
     }
 
     split_count
 }
 
+fn part1(grid: &mut Grid<Cell>) -> usize {
+    (0..grid.height() - 1).map(|y| step_row(grid, y)).sum()
+}
+
+pub fn visualize() {
+    let mut grid = read_and_parse("inputs/day07.txt");
+    let mut frames = vec![grid.clone()];
+    for y in 0..grid.height() - 1 {
+        step_row(&mut grid, y);
+        frames.push(grid.clone());
+    }
+    let style = |cell: &Cell| match cell {
+        Empty => ('.', [90, 90, 90]),
+        Start(_) => ('S', [255, 215, 0]),
+        Split(0) => ('^', [120, 120, 160]),
+        Split(_) => ('^', [255, 80, 80]),
+        Beam(_) => ('|', [80, 200, 255]),
+    };
+    Terminal::new(Duration::from_millis(30)).play(frames, style).expect("Failed to draw frames");
+}
+
 fn part2(grid: &mut Grid<Cell>) -> usize {
     let last_row = grid.height() - 1;
-    (0..grid.width())
-        .map(|x| grid[(x, last_row)].get_value())
-        .sum()
+    checked::sum((0..grid.width()).map(|x| grid[(x, last_row)].get_value()), "sum of timelines")
 }
 
 pub fn solve() -> SolutionPair {
@@ -1,40 +1,9 @@
+use crate::etc::checked;
+use crate::etc::point3::Point3;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
-use std::str::FromStr;
 use std::collections::HashSet;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Box {
-    x: usize,
-    y: usize,
-    z: usize,
-}
-
-impl FromStr for Box {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let dims: Vec<usize> = s
-            .split(',')
-            .map(|d| d.parse().unwrap())
-            .collect();
-        Ok(Box {
-            x: dims[0],
-            y: dims[1],
-            z: dims[2],
-        })
-    }
-}
-
-impl Box {
-    fn distance(&self, other: &Self) -> u64 {
-        let dx = self.x as isize - other.x as isize;
-        let dy = self.y as isize - other.y as isize;
-        let dz = self.z as isize - other.z as isize;
-        (dx * dx + dy * dy + dz * dz) as u64
-    }
-}
-
 struct Dsu {
     parent: Vec<usize>,
     size: Vec<usize>,
@@ -71,7 +40,7 @@ impl Dsu {
     }
 }
 
-fn read_and_parse(path: &str) -> Vec<Box> {
+fn read_and_parse(path: &str) -> Vec<Point3> {
     let input = read_to_string(path).expect("Failed to read input file");
     input
         .lines()
@@ -79,17 +48,17 @@ fn read_and_parse(path: &str) -> Vec<Box> {
         .collect()
 }
 
-fn processing(boxes: &[Box], pair_count: usize) -> (Vec<(u64, usize, usize)>, Dsu) {
+fn processing(boxes: &[Point3], pair_count: usize) -> (Vec<(u64, usize, usize)>, Dsu) {
     let n = boxes.len();
     let mut edges = Vec::with_capacity(n * (n - 1) / 2);
     for i in 0..(n - 1) {
         for j in (1 + i)..n {
-            let dist = boxes[i].distance(&boxes[j]);
+            let dist = boxes[i].squared_distance(&boxes[j]);
             edges.push((dist, i, j));
         }
     }
     edges.select_nth_unstable_by(pair_count, |a, b| a.0.cmp(&b.0));
-    edges[..pair_count].sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    edges[..pair_count].sort_unstable_by_key(|a| a.0);
 
     let mut dsu = Dsu::new(n);
     for &(_, a, b) in edges.iter().take(pair_count) {
@@ -106,19 +75,19 @@ fn part1(dsu: &mut Dsu) -> usize {
         sizes[p] += 1;
     }
     sizes.sort_by(|a, b| b.cmp(a));
-    sizes.iter().take(3).product()
+    checked::product(sizes.iter().take(3).copied(), "product of circuit sizes")
 }
-fn part2(boxes: &[Box], pair_count: usize, edges: &mut [(u64, usize, usize)], mut dsu: Dsu) -> usize {
+fn part2(boxes: &[Point3], pair_count: usize, edges: &mut [(u64, usize, usize)], mut dsu: Dsu) -> usize {
     let n = boxes.len();
     let mut skip = pair_count;
     loop {
         edges[skip..].select_nth_unstable_by(4 * pair_count, |a, b| a.0.cmp(&b.0));
         let next_skip = skip + 4 * pair_count;
-        edges[skip..next_skip].sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        edges[skip..next_skip].sort_unstable_by_key(|a| a.0);
         for &(_, a, b) in edges.iter().take(next_skip).skip(skip) {
             dsu.union(a, b);
             if dsu.network_count(a) == n {
-                return boxes[a].x * boxes[b].x;
+                return checked::mul(boxes[a].x as usize, boxes[b].x as usize, "x * x");
             }
         }
         skip = next_skip;
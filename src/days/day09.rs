@@ -1,34 +1,9 @@
 use crate::etc::point::Point;
+use crate::etc::polygon::Polygon;
+use crate::etc::rect::Rect;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 
-struct Rectangle {
-    min_x: isize,
-    max_x: isize,
-    min_y: isize,
-    max_y: isize,
-}
-
-impl Rectangle {
-    fn new(a: Point, b: Point) -> Self {
-        Rectangle {
-            min_x: a.x.min(b.x),
-            max_x: a.x.max(b.x),
-            min_y: a.y.min(b.y),
-            max_y: a.y.max(b.y),
-        }
-    }
-    fn area(&self) -> u64 {
-        ((self.max_x - self.min_x + 1) as u64) * ((self.max_y - self.min_y + 1) as u64)
-    }
-    fn exceeds_rect(&self, other: &Rectangle) -> bool {
-        self.min_x < other.max_x
-            && self.max_x > other.min_x
-            && self.min_y < other.max_y
-            && self.max_y > other.min_y
-    }
-}
-
 fn read_and_parse(path: &str) -> Vec<Point> {
     let input = read_to_string(path).expect("Failed to read input file");
     input
@@ -45,32 +20,24 @@ fn read_and_parse(path: &str) -> Vec<Point> {
 fn part1(points: &[Point]) -> u64 {
     (0..points.len() - 1)
         .flat_map(|i| {
-            (i + 1..points.len()).map(move |j| Rectangle::new(points[i], points[j]).area())
+            (i + 1..points.len()).map(move |j| Rect::new(points[i], points[j]).area())
         })
         .max()
         .unwrap()
 }
 
 fn part2(points: &[Point]) -> u64 {
-    let mut greens: Vec<_> = points
-        .windows(2)
-        .map(|w| Rectangle::new(w[0], w[1]))
-        .collect();
-    greens.push(Rectangle::new(
-        points.first().cloned().unwrap(),
-        points.last().cloned().unwrap(),
-    ));
-
+    let polygon = Polygon::new(points.to_vec());
     let mut max_area = 0;
 
     for i in 0..(points.len() - 1) {
         for j in (i + 1)..points.len() {
-            let rectangle = Rectangle::new(points[i], points[j]);
+            let rectangle = Rect::new(points[i], points[j]);
             let area = rectangle.area();
-            if area < max_area {
+            if area <= max_area {
                 continue;
             }
-            if greens.iter().all(|g| !rectangle.exceeds_rect(g)) {
+            if polygon.contains_rect(&rectangle) {
                 max_area = area;
             }
         }
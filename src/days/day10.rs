@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::SolutionPair;
 use good_lp::*;
 use std::collections::{HashSet, VecDeque};
@@ -98,7 +99,7 @@ impl Machine {
             problem.add_constraint(constraint!(expr.clone() == self.energy[i] as f64));
         }
         let solution = problem.solve().unwrap();
-        press_vars.iter().map(|v| solution.value(*v) as usize).sum()
+        checked::sum(press_vars.iter().map(|v| solution.value(*v) as usize), "button presses")
     }
 }
 
@@ -110,13 +111,10 @@ fn read_and_parse(path: &str) -> Vec<Machine> {
         .collect()
 }
 fn part1(machines: &[Machine]) -> usize {
-    machines.iter().map(Machine::count_buttons_pressed).sum()
+    checked::sum(machines.iter().map(Machine::count_buttons_pressed), "total presses")
 }
 fn part2(machines: &[Machine]) -> usize {
-    machines
-        .iter()
-        .map(Machine::count_energy_buttons_pressed)
-        .sum()
+    checked::sum(machines.iter().map(Machine::count_energy_buttons_pressed), "total presses")
 }
 
 pub fn solve() -> SolutionPair {
@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::{Solution, SolutionPair};
 use std::collections::HashMap;
 use std::fs::read_to_string;
@@ -32,7 +33,7 @@ fn dfs(
     let mut total_paths = 0;
     if let Some(neighbors) = map.get(current) {
         for neighbor in neighbors {
-            total_paths += dfs(map, neighbor, end, cache);
+            total_paths = checked::add(total_paths, dfs(map, neighbor, end, cache), "path count");
         }
     }
     cache.insert(current.to_string(), total_paths);
@@ -45,13 +46,13 @@ fn part2(map: &HashMap<String, Vec<String>>) -> usize {
     let dac_fft_paths = paths_count(map, "dac", "fft");
     let fft_dac_paths = paths_count(map, "fft", "dac");
     if dac_fft_paths != 0 {
-        let srv_dac_paths = paths_count(map, "srv", "dac");
+        let svr_dac_paths = paths_count(map, "svr", "dac");
         let fft_out_paths = paths_count(map, "fft", "out");
-        srv_dac_paths * dac_fft_paths * fft_out_paths
+        checked::product([svr_dac_paths, dac_fft_paths, fft_out_paths], "path count product")
     } else {
-        let srv_fft_paths = paths_count(map, "srv", "fft");
+        let svr_fft_paths = paths_count(map, "svr", "fft");
         let dac_out_paths = paths_count(map, "dac", "out");
-        srv_fft_paths * fft_dac_paths * dac_out_paths
+        checked::product([svr_fft_paths, fft_dac_paths, dac_out_paths], "path count product")
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::etc::checked;
 use crate::{Solution, SolutionPair};
 use std::fs::read_to_string;
 
@@ -13,11 +14,11 @@ fn part1(path: &str) -> usize {
             let mut splits = left.split('x');
             let x: u32 = splits.next().unwrap().parse().unwrap();
             let y: u32 = splits.next().unwrap().parse().unwrap();
-            let gift_count: u32 = right
-                .split_whitespace()
-                .map(|num| num.parse::<u32>().unwrap())
-                .sum();
-            if x / 3 * y / 3 >= gift_count {
+            let gift_count = checked::sum(
+                right.split_whitespace().map(|num| num.parse::<u32>().unwrap()),
+                "gift count",
+            );
+            if checked::mul(x / 3, y, "region size") / 3 >= gift_count {
                 Some(())
             } else {
                 None
@@ -25,13 +26,9 @@ fn part1(path: &str) -> usize {
         })
         .count()
 }
-fn part2(_data: &str) -> u64 {
-    0
-}
 
 pub fn solve() -> SolutionPair {
     let sol1 = part1("inputs/day12.txt");
-    let sol2 = part2("");
 
-    (Solution::from(sol1), Solution::from(sol2))
+    (Solution::from(sol1), Solution::Unsolved)
 }
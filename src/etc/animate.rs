@@ -0,0 +1,82 @@
+use std::io::{self, IsTerminal, Write};
+use std::thread::sleep;
+use std::time::Duration;
+
+use super::{Grid, Rgb};
+
+// Plays grid frames in place with ANSI escapes. When stdout is not a
+// terminal only the last frame is printed, as plain text.
+#[derive(Debug, Clone, Copy)]
+pub struct Terminal {
+    delay: Duration,
+    tty: bool,
+}
+
+impl Terminal {
+    pub fn new(delay: Duration) -> Self {
+        Self { delay, tty: io::stdout().is_terminal() }
+    }
+    pub fn play<T>(
+        &self,
+        frames: impl IntoIterator<Item = Grid<T>>,
+        style: impl Fn(&T) -> (char, Rgb),
+    ) -> io::Result<()> {
+        let mut out = io::stdout().lock();
+        if !self.tty {
+            if let Some(last) = frames.into_iter().last() {
+                write!(out, "{}", render(&last, &style, false))?;
+            }
+            return Ok(());
+        }
+
+        write!(out, "\x1b[?25l")?;
+        let mut height = 0;
+        for frame in frames {
+            if height > 0 {
+                write!(out, "\x1b[{}A", height)?;
+            }
+            write!(out, "{}", render(&frame, &style, true))?;
+            out.flush()?;
+            height = frame.height();
+            sleep(self.delay);
+        }
+        write!(out, "\x1b[?25h")?;
+        out.flush()
+    }
+}
+
+fn render<T>(grid: &Grid<T>, style: impl Fn(&T) -> (char, Rgb), color: bool) -> String {
+    let mut s = String::new();
+    for row in grid.rows() {
+        let mut last = None;
+        for cell in row {
+            let (c, rgb) = style(cell);
+            if color && last != Some(rgb) {
+                s.push_str(&format!("\x1b[38;2;{};{};{}m", rgb[0], rgb[1], rgb[2]));
+                last = Some(rgb);
+            }
+            s.push(c);
+        }
+        if color {
+            s.push_str("\x1b[0m");
+        }
+        s.push('\n');
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_str("#.\n..\n");
+        let style = |&c: &char| (c, if c == '#' { [255, 0, 0] } else { [9, 9, 9] });
+        assert_eq!(render(&grid, style, false), "#.\n..\n");
+        assert_eq!(
+            render(&grid, style, true),
+            "\x1b[38;2;255;0;0m#\x1b[38;2;9;9;9m.\x1b[0m\n\x1b[38;2;9;9;9m..\x1b[0m\n"
+        );
+    }
+}
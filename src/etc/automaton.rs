@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{Grid, Neighborhood};
+
+// Steps a grid with a rule that maps a cell and its in-bounds neighbors to
+// the cell's next state. Two buffers are swapped each generation.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    current: Grid<T>,
+    next: Grid<T>,
+    neighborhood: Neighborhood,
+    generation: usize,
+}
+
+impl<T: Clone + PartialEq> Automaton<T> {
+    pub fn new(grid: Grid<T>, neighborhood: Neighborhood) -> Self {
+        Self { next: grid.clone(), current: grid, neighborhood, generation: 0 }
+    }
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    // Advances one generation and returns how many cells changed.
+    pub fn step(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> usize {
+        let mut changes = 0;
+        let mut neighbors = Vec::with_capacity(8);
+        for (pos, cell) in self.current.enumerate() {
+            neighbors.clear();
+            neighbors.extend(
+                self.neighborhood
+                    .neighbors_in_bounds(pos, &self.current)
+                    .map(|n| &self.current[n]),
+            );
+            let new = rule(cell, &neighbors);
+            if new != *cell {
+                changes += 1;
+            }
+            self.next[pos] = new;
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changes
+    }
+    pub fn step_n(&mut self, n: usize, rule: impl Fn(&T, &[&T]) -> T) -> Vec<usize> {
+        (0..n).map(|_| self.step(&rule)).collect()
+    }
+    // Steps until a generation changes nothing and returns the change counts
+    // of the generations before it.
+    pub fn run_until_stable(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> Vec<usize> {
+        let mut counts = Vec::new();
+        loop {
+            match self.step(&rule) {
+                0 => return counts,
+                changes => counts.push(changes),
+            }
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> Automaton<T> {
+    // Steps until a state repeats and returns the generation where the cycle
+    // starts and its period. The automaton is left at the repeated state.
+    pub fn find_cycle(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> (usize, usize) {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(&start) = seen.get(&self.current) {
+                return (start, self.generation - start);
+            }
+            seen.insert(self.current.clone(), self.generation);
+            self.step(&rule);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(&alive: &bool, neighbors: &[&bool]) -> bool {
+        let n = neighbors.iter().filter(|&&&b| b).count();
+        n == 3 || (alive && n == 2)
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let grid = Grid::from_str("@@@@..\n@@@@..\n@@@@.@\n@@@@..\n");
+        let mut automaton = Automaton::new(grid, Neighborhood::Eight);
+        let counts = automaton.run_until_stable(|&c, neighbors| {
+            let rolls = neighbors.iter().filter(|&&&n| n == '@').count();
+            if c == '@' && rolls < 4 { '.' } else { c }
+        });
+        assert_eq!(counts, [5]);
+        assert_eq!(automaton.generation(), 2);
+        assert_eq!(automaton.grid(), &Grid::from_str(".@@...\n@@@@..\n@@@@..\n.@@...\n"));
+    }
+    #[test]
+    fn test_cycles() {
+        let blinker = Grid::map_from_str(".....\n..#..\n..#..\n..#..\n.....\n", |c| c == '#');
+        let flipped = Grid::map_from_str(".....\n.....\n.###.\n.....\n.....\n", |c| c == '#');
+        let mut automaton = Automaton::new(blinker.clone(), Neighborhood::Eight);
+        assert_eq!(automaton.step(life), 4);
+        assert_eq!(automaton.grid(), &flipped);
+        assert_eq!(automaton.step(life), 4);
+        assert_eq!(automaton.grid(), &blinker);
+        assert_eq!(automaton.find_cycle(life), (2, 2));
+
+        let glider = Grid::map_from_str(".#...\n..#..\n###..\n.....\n.....\n", |c| c == '#');
+        let mut automaton = Automaton::new(glider, Neighborhood::Eight);
+        let (start, period) = automaton.find_cycle(life);
+        assert_eq!(period, 1);
+        assert!(start > 0);
+        assert_eq!(automaton.grid().iter().filter(|&&b| b).count(), 4);
+    }
+}
@@ -0,0 +1,483 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    // Little-endian base 2^32 limbs without trailing zeros.
+    limbs: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+    pub fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(self.limbs.iter().rev().fold(0u128, |acc, &l| (acc << 32) | l as u128))
+    }
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "division by zero");
+        if self < other {
+            return (Self::zero(), self.clone());
+        }
+        if other.limbs.len() == 1 {
+            let (q, r) = self.div_rem_small(other.limbs[0]);
+            return (q, Self::from(r));
+        }
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = Self::zero();
+        for bit in (0..self.bits()).rev() {
+            remainder.shl1();
+            if self.bit(bit) {
+                remainder.set_low_bit();
+            }
+            if remainder >= *other {
+                remainder = &remainder - other;
+                quotient[bit / 32] |= 1 << (bit % 32);
+            }
+        }
+        (Self::from_limbs(quotient), remainder)
+    }
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+    fn bit(&self, i: usize) -> bool {
+        self.limbs[i / 32] >> (i % 32) & 1 == 1
+    }
+    fn shl1(&mut self) {
+        let mut carry = 0;
+        for limb in self.limbs.iter_mut() {
+            let next = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+    fn set_low_bit(&mut self) {
+        match self.limbs.first_mut() {
+            Some(low) => *low |= 1,
+            None => self.limbs.push(1),
+        }
+    }
+    fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            let v = *limb as u64 * factor as u64 + carry;
+            *limb = v as u32;
+            carry = v >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+        *self = Self::from_limbs(std::mem::take(&mut self.limbs));
+    }
+    fn div_rem_small(&self, divisor: u32) -> (Self, u32) {
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut rem = 0u64;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let v = (rem << 32) | limb as u64;
+            quotient[i] = (v / divisor as u64) as u32;
+            rem = v % divisor as u64;
+        }
+        (Self::from_limbs(quotient), rem as u32)
+    }
+    fn add_ref(&self, other: &Self) -> Self {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (&self.limbs, &other.limbs)
+        } else {
+            (&other.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0u64;
+        for (i, &l) in long.iter().enumerate() {
+            let v = l as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(v as u32);
+            carry = v >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        Self { limbs }
+    }
+    fn sub_ref(&self, other: &Self) -> Self {
+        assert!(*self >= *other, "BigUint subtraction underflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, &l) in self.limbs.iter().enumerate() {
+            let mut v = l as i64 - *other.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = 0;
+            if v < 0 {
+                v += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(v as u32);
+        }
+        Self::from_limbs(limbs)
+    }
+    fn mul_ref(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let v = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = v as u32;
+                carry = v >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Self::from_limbs(limbs)
+    }
+    fn div_ref(&self, other: &Self) -> Self {
+        self.div_rem(other).0
+    }
+    fn rem_ref(&self, other: &Self) -> Self {
+        self.div_rem(other).1
+    }
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+    pub fn abs(&self) -> Self {
+        Self::from(self.magnitude.clone())
+    }
+    pub fn to_i128(&self) -> Option<i128> {
+        let m = self.magnitude.to_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(m)
+        } else {
+            i128::try_from(m).ok()
+        }
+    }
+    fn from_parts(negative: bool, magnitude: BigUint) -> Self {
+        let negative = negative && !magnitude.is_zero();
+        Self { negative, magnitude }
+    }
+    fn add_ref(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self::from_parts(self.negative, &self.magnitude + &other.magnitude);
+        }
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => Self::from_parts(other.negative, &other.magnitude - &self.magnitude),
+            _ => Self::from_parts(self.negative, &self.magnitude - &other.magnitude),
+        }
+    }
+    fn sub_ref(&self, other: &Self) -> Self {
+        self.add_ref(&-other)
+    }
+    fn mul_ref(&self, other: &Self) -> Self {
+        Self::from_parts(self.negative != other.negative, &self.magnitude * &other.magnitude)
+    }
+    // Division truncates toward zero and the remainder takes the dividend's
+    // sign, matching the primitive integer types.
+    fn div_ref(&self, other: &Self) -> Self {
+        Self::from_parts(self.negative != other.negative, &self.magnitude / &other.magnitude)
+    }
+    fn rem_ref(&self, other: &Self) -> Self {
+        Self::from_parts(self.negative, &self.magnitude % &other.magnitude)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (q, r) = rest.div_rem_small(1_000_000_000);
+            chunks.push(r);
+            rest = q;
+        }
+        let mut s = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            s.push_str(&format!("{:09}", chunk));
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+        if s.is_empty() {
+            return Err(ParseBigIntError);
+        }
+        let mut n = Self::zero();
+        for c in s.chars() {
+            let digit = c.to_digit(10).ok_or(ParseBigIntError)?;
+            n.mul_add_small(10, digit);
+        }
+        Ok(n)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(rest) if !rest.starts_with('+') => Ok(Self::from_parts(true, rest.parse()?)),
+            Some(_) => Err(ParseBigIntError),
+            None => Ok(Self::from_parts(false, s.parse()?)),
+        }
+    }
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid big integer literal")
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($type_:ident),*) => {$(
+        impl From<$type_> for BigUint {
+            fn from(v: $type_) -> Self {
+                let mut v = v as u128;
+                let mut limbs = Vec::new();
+                while v != 0 {
+                    limbs.push(v as u32);
+                    v >>= 32;
+                }
+                Self { limbs }
+            }
+        }
+
+        impl From<$type_> for BigInt {
+            fn from(v: $type_) -> Self {
+                Self::from(BigUint::from(v))
+            }
+        }
+    )*}
+}
+
+macro_rules! impl_from_signed {
+    ($($type_:ident),*) => {$(
+        impl From<$type_> for BigInt {
+            fn from(v: $type_) -> Self {
+                Self::from_parts(v < 0, BigUint::from((v as i128).unsigned_abs()))
+            }
+        }
+    )*}
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self { negative: false, magnitude }
+    }
+}
+
+macro_rules! impl_binop {
+    ($type_:ident, $trait_:ident, $method:ident, $func:ident) => {
+        impl $trait_<&$type_> for &$type_ {
+            type Output = $type_;
+            fn $method(self, other: &$type_) -> $type_ {
+                self.$func(other)
+            }
+        }
+
+        impl $trait_<$type_> for $type_ {
+            type Output = $type_;
+            fn $method(self, other: $type_) -> $type_ {
+                self.$func(&other)
+            }
+        }
+
+        impl $trait_<&$type_> for $type_ {
+            type Output = $type_;
+            fn $method(self, other: &$type_) -> $type_ {
+                self.$func(other)
+            }
+        }
+
+        impl $trait_<$type_> for &$type_ {
+            type Output = $type_;
+            fn $method(self, other: $type_) -> $type_ {
+                self.$func(&other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_assign {
+    ($type_:ident, $trait_:ident, $method:ident, $func:ident) => {
+        impl $trait_<$type_> for $type_ {
+            fn $method(&mut self, other: $type_) {
+                *self = self.$func(&other);
+            }
+        }
+
+        impl $trait_<&$type_> for $type_ {
+            fn $method(&mut self, other: &$type_) {
+                *self = self.$func(other);
+            }
+        }
+    };
+}
+
+impl_binop!(BigUint, Add, add, add_ref);
+impl_binop!(BigUint, Sub, sub, sub_ref);
+impl_binop!(BigUint, Mul, mul, mul_ref);
+impl_binop!(BigUint, Div, div, div_ref);
+impl_binop!(BigUint, Rem, rem, rem_ref);
+impl_assign!(BigUint, AddAssign, add_assign, add_ref);
+impl_assign!(BigUint, SubAssign, sub_assign, sub_ref);
+impl_assign!(BigUint, MulAssign, mul_assign, mul_ref);
+
+impl_binop!(BigInt, Add, add, add_ref);
+impl_binop!(BigInt, Sub, sub, sub_ref);
+impl_binop!(BigInt, Mul, mul, mul_ref);
+impl_binop!(BigInt, Div, div, div_ref);
+impl_binop!(BigInt, Rem, rem, rem_ref);
+impl_assign!(BigInt, AddAssign, add_assign, add_ref);
+impl_assign!(BigInt, SubAssign, sub_assign, sub_ref);
+impl_assign!(BigInt, MulAssign, mul_assign, mul_ref);
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_display() {
+        for s in ["0", "7", "-1", "4294967296", "-340282366920938463463374607431768211456", "1000000000000000000000000000001"] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(big("-0"), BigInt::zero());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+    }
+    #[test]
+    fn test_arithmetic() {
+        let a = BigInt::from(u128::MAX);
+        let b = BigInt::from(-12345678901234567i64);
+        assert_eq!((&a + &a).to_string(), "680564733841876926926749214863536422910");
+        assert_eq!((&b - &a).to_string(), "-340282366920938463463386953110669446022");
+        assert_eq!((&a * &a).to_string(), "115792089237316195423570985008687907852589419931798687112530834793049593217025");
+        assert_eq!(&(&a * &b) / &b, a);
+        assert_eq!(&(&(&a * &a) + &b) % &a, b.clone() + &a);
+    }
+    #[test]
+    fn test_div_rem_matches_primitives() {
+        let values = [-1_000_000_007i128, -97, -1, 1, 13, 4_294_967_297, i64::MAX as i128 * 3];
+        for &x in &values {
+            for &y in &values {
+                assert_eq!((BigInt::from(x) / BigInt::from(y)).to_i128(), Some(x / y));
+                assert_eq!((BigInt::from(x) % BigInt::from(y)).to_i128(), Some(x % y));
+            }
+        }
+    }
+    #[test]
+    fn test_ordering() {
+        assert!(big("-5") < big("-4"));
+        assert!(big("-5") < big("0"));
+        assert!(big("18446744073709551616") > big("18446744073709551615"));
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(big("170141183460469231731687303715884105728").to_i128(), None);
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use super::{Grid, Point};
+
+// One bit per cell, each row packed into `words` u64s with x = 0 in the
+// lowest bit. Padding bits past `width` are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words: usize,
+    data: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let words = width.div_ceil(64);
+        Self { width, height, words, data: vec![0; words * height] }
+    }
+    pub fn from_grid<T>(grid: &Grid<T>, pred: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (p, v) in grid.enumerate() {
+            if pred(v) {
+                bits.set(p.x as usize, p.y as usize, true);
+            }
+        }
+        bits
+    }
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |x, y| self.get(x, y))
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        assert!(x < self.width && y < self.height, "point out of bounds: ({}, {})", x, y);
+        self.data[y * self.words + x / 64] >> (x % 64) & 1 == 1
+    }
+    pub fn get_point(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+            && self.get(p.x as usize, p.y as usize)
+    }
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(x < self.width && y < self.height, "point out of bounds: ({}, {})", x, y);
+        let word = &mut self.data[y * self.words + x / 64];
+        if value {
+            *word |= 1 << (x % 64);
+        } else {
+            *word &= !(1 << (x % 64));
+        }
+    }
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|w| w.count_ones() as usize).sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&w| w == 0)
+    }
+    pub fn ones(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(move |y| {
+            (0..self.width)
+                .filter(move |&x| self.get(x, y))
+                .map(move |x| Point::new(x as isize, y as isize))
+        })
+    }
+    // Number of set cells among the 8 neighbors of each cell in row `y`.
+    pub fn neighbor_counts(&self, y: usize) -> Vec<u8> {
+        let planes = self.count_planes(y);
+        (0..self.width)
+            .map(|x| {
+                let [b0, b1, b2, b3] = planes[x / 64];
+                let bit = |w: u64| (w >> (x % 64) & 1) as u8;
+                bit(b0) | bit(b1) << 1 | bit(b2) << 2 | bit(b3) << 3
+            })
+            .collect()
+    }
+    // Mask of the cells, set or not, with fewer than `k` set neighbors.
+    pub fn fewer_neighbors_than(&self, k: u8) -> Self {
+        let mut mask = Self::new(self.width, self.height);
+        for y in 0..self.height {
+            for (i, [b0, b1, b2, b3]) in self.count_planes(y).into_iter().enumerate() {
+                let (mut greater, mut equal) = (0u64, !0u64);
+                if k > 15 {
+                    equal = 0;
+                }
+                for (bit, plane) in [(3, b3), (2, b2), (1, b1), (0, b0)] {
+                    if k >> bit & 1 == 1 {
+                        equal &= plane;
+                    } else {
+                        greater |= equal & plane;
+                        equal &= !plane;
+                    }
+                }
+                mask.data[y * self.words + i] = !(greater | equal);
+            }
+        }
+        mask.clear_padding();
+        mask
+    }
+    // Bit-sliced sum of the eight shifted neighbor rows: bit i of the count
+    // for cell x is bit x of plane i.
+    fn count_planes(&self, y: usize) -> Vec<[u64; 4]> {
+        let empty = vec![0u64; self.words];
+        let row = |y: Option<usize>| match y {
+            Some(y) if y < self.height => &self.data[y * self.words..(y + 1) * self.words],
+            _ => &empty[..],
+        };
+        let rows = [row(y.checked_sub(1)), row(Some(y)), row(Some(y + 1))];
+
+        (0..self.words)
+            .map(|i| {
+                let mut planes = [0u64; 4];
+                for (r, cells) in rows.iter().enumerate() {
+                    let from_left = cells[i] << 1 | if i > 0 { cells[i - 1] >> 63 } else { 0 };
+                    let from_right = cells[i] >> 1 | cells.get(i + 1).map_or(0, |w| w << 63);
+                    add_plane(&mut planes, from_left);
+                    add_plane(&mut planes, from_right);
+                    if r != 1 {
+                        add_plane(&mut planes, cells[i]);
+                    }
+                }
+                planes
+            })
+            .collect()
+    }
+    fn clear_padding(&mut self) {
+        if self.width.is_multiple_of(64) {
+            return;
+        }
+        let mask = (1u64 << (self.width % 64)) - 1;
+        for row in self.data.chunks_mut(self.words) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+    fn zip_with(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!((self.width, self.height), (other.width, other.height), "grid sizes differ");
+        for (a, &b) in self.data.iter_mut().zip(&other.data) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+fn add_plane(planes: &mut [u64; 4], mut carry: u64) {
+    for plane in planes.iter_mut() {
+        let next = *plane & carry;
+        *plane ^= carry;
+        carry = next;
+    }
+}
+
+macro_rules! impl_bitop {
+    ($trait_:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $assign_trait<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                self.zip_with(other, |a, b| a $op b);
+            }
+        }
+
+        impl $trait_<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.zip_with(other, |a, b| a $op b);
+                result
+            }
+        }
+    };
+}
+
+impl_bitop!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bitop!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bitop!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+    fn not(self) -> BitGrid {
+        let mut result = self.clone();
+        for w in result.data.iter_mut() {
+            *w = !*w;
+        }
+        result.clear_padding();
+        result
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid::from_str(s), |&c| c == '#')
+    }
+
+    #[test]
+    fn test_neighbor_counts() {
+        let grid = bits("##.#\n###.\n.#..\n");
+        assert_eq!(grid.neighbor_counts(0), [3, 4, 4, 1]);
+        assert_eq!(grid.neighbor_counts(1), [4, 5, 4, 2]);
+        assert_eq!(grid.neighbor_counts(2), [3, 3, 3, 1]);
+    }
+    #[test]
+    fn test_removal_until_stable() {
+        let mut grid = bits("####..\n####..\n####.#\n####..\n");
+        let accessible = &grid & &grid.fewer_neighbors_than(4);
+        assert_eq!(accessible, bits("#..#..\n......\n.....#\n#..#..\n"));
+
+        loop {
+            let removed = &grid & &grid.fewer_neighbors_than(4);
+            if removed.is_empty() {
+                break;
+            }
+            grid ^= &removed;
+        }
+        assert_eq!(grid, bits(".##...\n####..\n####..\n.##...\n"));
+    }
+    #[test]
+    fn test_wide_rows() {
+        let mut grid = BitGrid::new(130, 3);
+        for x in [0, 63, 64, 65, 127, 128, 129] {
+            grid.set(x, 1, true);
+        }
+        let counts = grid.neighbor_counts(0);
+        assert_eq!((counts[62], counts[63], counts[64], counts[66], counts[129]), (1, 2, 3, 1, 2));
+        assert_eq!((!&grid).count_ones(), 130 * 3 - 7);
+        assert_eq!((&grid | &!&grid).count_ones(), 390);
+        assert_eq!((&grid ^ &grid).count_ones(), 0);
+    }
+}
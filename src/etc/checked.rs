@@ -0,0 +1,92 @@
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+
+use num_traits::PrimInt;
+
+use super::AocError;
+
+// With the `checked` feature these helpers unwind with an `Overflow` payload
+// instead of wrapping, and `run` turns that into an `AocError` for the day.
+// Without it they compile down to the plain operators.
+#[derive(Debug)]
+pub struct Overflow(pub &'static str);
+
+#[cfg(feature = "checked")]
+fn overflow<T>(op: &'static str) -> T {
+    panic::panic_any(Overflow(op))
+}
+
+#[cfg(feature = "checked")]
+pub fn add<T: PrimInt>(a: T, b: T, op: &'static str) -> T {
+    a.checked_add(&b).unwrap_or_else(|| overflow(op))
+}
+
+#[cfg(feature = "checked")]
+pub fn sub<T: PrimInt>(a: T, b: T, op: &'static str) -> T {
+    a.checked_sub(&b).unwrap_or_else(|| overflow(op))
+}
+
+#[cfg(feature = "checked")]
+pub fn mul<T: PrimInt>(a: T, b: T, op: &'static str) -> T {
+    a.checked_mul(&b).unwrap_or_else(|| overflow(op))
+}
+
+#[cfg(not(feature = "checked"))]
+#[inline(always)]
+pub fn add<T: PrimInt>(a: T, b: T, _op: &'static str) -> T {
+    a + b
+}
+
+#[cfg(not(feature = "checked"))]
+#[inline(always)]
+pub fn sub<T: PrimInt>(a: T, b: T, _op: &'static str) -> T {
+    a - b
+}
+
+#[cfg(not(feature = "checked"))]
+#[inline(always)]
+pub fn mul<T: PrimInt>(a: T, b: T, _op: &'static str) -> T {
+    a * b
+}
+
+pub fn sum<T: PrimInt>(iter: impl IntoIterator<Item = T>, op: &'static str) -> T {
+    iter.into_iter().fold(T::zero(), |acc, v| add(acc, v, op))
+}
+
+pub fn product<T: PrimInt>(iter: impl IntoIterator<Item = T>, op: &'static str) -> T {
+    iter.into_iter().fold(T::one(), |acc, v| mul(acc, v, op))
+}
+
+pub fn run<R>(day: u8, f: impl FnOnce() -> R + UnwindSafe) -> Result<R, AocError> {
+    if !cfg!(feature = "checked") {
+        return Ok(f());
+    }
+    static QUIET_OVERFLOW: Once = Once::new();
+    QUIET_OVERFLOW.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if info.payload().downcast_ref::<Overflow>().is_none() {
+                hook(info);
+            }
+        }));
+    });
+    match panic::catch_unwind(f) {
+        Ok(r) => Ok(r),
+        Err(payload) => match payload.downcast::<Overflow>() {
+            Ok(overflow) => Err(AocError::Overflow { day, op: overflow.0 }),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+#[cfg(all(test, feature = "checked"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let result = run(6, || product([u64::MAX, 2], "column product"));
+        assert_eq!(result, Err(AocError::Overflow { day: 6, op: "column product" }));
+        assert_eq!(run(2, || sum([1u64, 2, 3], "sum")), Ok(6));
+    }
+}
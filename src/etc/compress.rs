@@ -0,0 +1,178 @@
+use super::{checked, Grid, Point, Rect};
+
+// One compressed axis. Every distinct value gets a cell of size 1, and every
+// run of skipped values between two of them gets a single gap cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    starts: Vec<isize>,
+    sizes: Vec<u64>,
+}
+
+impl Axis {
+    pub fn new(values: impl IntoIterator<Item = isize>) -> Self {
+        let mut values: Vec<isize> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        let (mut starts, mut sizes) = (Vec::new(), Vec::new());
+        for (i, &v) in values.iter().enumerate() {
+            if i > 0 && v - values[i - 1] > 1 {
+                starts.push(values[i - 1] + 1);
+                sizes.push((v - values[i - 1] - 1) as u64);
+            }
+            starts.push(v);
+            sizes.push(1);
+        }
+        Self { starts, sizes }
+    }
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+    // Cell covering `v`, if `v` lies between the smallest and largest value.
+    pub fn index(&self, v: isize) -> Option<usize> {
+        let i = self.starts.partition_point(|&s| s <= v).checked_sub(1)?;
+        (v < self.starts[i] + self.sizes[i] as isize).then_some(i)
+    }
+    pub fn start(&self, i: usize) -> isize {
+        self.starts[i]
+    }
+    pub fn size(&self, i: usize) -> u64 {
+        self.sizes[i]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compressed {
+    pub x: Axis,
+    pub y: Axis,
+}
+
+impl Compressed {
+    pub fn new(points: &[Point]) -> Self {
+        Self {
+            x: Axis::new(points.iter().map(|p| p.x)),
+            y: Axis::new(points.iter().map(|p| p.y)),
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.x.len()
+    }
+    pub fn height(&self) -> usize {
+        self.y.len()
+    }
+    pub fn compress(&self, p: Point) -> Option<Point> {
+        Some(Point::new(self.x.index(p.x)? as isize, self.y.index(p.y)? as isize))
+    }
+    // The real cells covered by a compressed cell.
+    pub fn expand(&self, cell: Point) -> Rect {
+        let (i, j) = (cell.x as usize, cell.y as usize);
+        let min = Point::new(self.x.start(i), self.y.start(j));
+        Rect::new(min, min + Point::new(self.x.size(i) as isize - 1, self.y.size(j) as isize - 1))
+    }
+    pub fn cell_area(&self, cell: Point) -> u64 {
+        self.expand(cell).area()
+    }
+    // Compressed grid with each cell built from the real rectangle it covers.
+    pub fn grid<T>(&self, f: impl Fn(Rect) -> T) -> Grid<T> {
+        Grid::from_fn(self.width(), self.height(), |x, y| f(self.expand(Point::new(x as isize, y as isize))))
+    }
+    // Real area of each compressed cell.
+    pub fn areas(&self) -> Grid<u64> {
+        self.grid(|r| r.area())
+    }
+}
+
+// Inclusive 2D prefix sums: any rectangle of cells sums in O(1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum {
+    sums: Grid<u64>,
+}
+
+impl PrefixSum {
+    pub fn new(grid: &Grid<u64>) -> Self {
+        let mut sums = Grid::new(grid.width() + 1, grid.height() + 1, 0);
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let rest = sums[(x + 1, y)] - sums[(x, y)] + sums[(x, y + 1)];
+                sums[(x + 1, y + 1)] = checked::add(rest, grid[(x, y)], "prefix sum");
+            }
+        }
+        Self { sums }
+    }
+    // Sum over the cells of `rect`, given in grid coordinates.
+    pub fn sum(&self, rect: &Rect) -> u64 {
+        let (x0, y0) = (rect.min.x as usize, rect.min.y as usize);
+        let (x1, y1) = (rect.max.x as usize + 1, rect.max.y as usize + 1);
+        self.sums[(x1, y1)] + self.sums[(x0, y0)] - self.sums[(x0, y1)] - self.sums[(x1, y0)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::Polygon;
+
+    // The red tiles of the day 9 example.
+    fn red_tiles() -> Vec<Point> {
+        vec![
+            Point::new(7, 1),
+            Point::new(11, 1),
+            Point::new(11, 7),
+            Point::new(9, 7),
+            Point::new(9, 5),
+            Point::new(2, 5),
+            Point::new(2, 3),
+            Point::new(7, 3),
+        ]
+    }
+
+    #[test]
+    fn test_axis() {
+        let axis = Axis::new([7, 2, 11, 9, 2, 10]);
+        let cells: Vec<(isize, u64)> = (0..axis.len()).map(|i| (axis.start(i), axis.size(i))).collect();
+        assert_eq!(cells, [(2, 1), (3, 4), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1)]);
+        assert_eq!(axis.index(5), Some(1));
+        assert_eq!(axis.index(11), Some(6));
+        assert_eq!(axis.index(1), None);
+        assert_eq!(axis.index(12), None);
+    }
+    #[test]
+    fn test_areas_sum_to_bounding_box() {
+        let points = red_tiles();
+        let compressed = Compressed::new(&points);
+        assert_eq!((compressed.width(), compressed.height()), (7, 7));
+        assert_eq!(compressed.areas().iter().sum::<u64>(), 10 * 7);
+        assert_eq!(compressed.compress(Point::new(5, 6)), Some(Point::new(1, 5)));
+        assert_eq!(compressed.expand(Point::new(1, 5)), Rect::new(Point::new(3, 6), Point::new(6, 6)));
+    }
+    #[test]
+    fn test_day09_with_prefix_sums() {
+        let points = red_tiles();
+        let polygon = Polygon::new(points.clone());
+        let compressed = Compressed::new(&points);
+        let inside = compressed.grid(|r| polygon.contains(r.min));
+        assert_eq!(compressed.grid(|r| r.area() * polygon.contains(r.min) as u64).iter().sum::<u64>(), 46);
+
+        let outside = PrefixSum::new(&Grid::from_fn(inside.width(), inside.height(), |x, y| !inside[(x, y)] as u64));
+        let mut best = 0;
+        for (i, &a) in points.iter().enumerate() {
+            for &b in &points[i + 1..] {
+                let cells = Rect::new(compressed.compress(a).unwrap(), compressed.compress(b).unwrap());
+                if outside.sum(&cells) == 0 {
+                    best = best.max(Rect::new(a, b).area());
+                }
+            }
+        }
+        assert_eq!(best, 24);
+    }
+    #[test]
+    fn test_prefix_sum() {
+        let grid = Grid::from_fn(4, 3, |x, y| (x + 4 * y) as u64);
+        let sums = PrefixSum::new(&grid);
+        assert_eq!(sums.sum(&Rect::new(Point::new(0, 0), Point::new(3, 2))), 66);
+        assert_eq!(sums.sum(&Rect::new(Point::new(1, 1), Point::new(2, 2))), 5 + 6 + 9 + 10);
+        assert_eq!(sums.sum(&Rect::new(Point::new(3, 0), Point::new(3, 0))), 3);
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+
+use super::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+// Clockwise from Up, so turning is a step through ALL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+    pub fn to_point(self) -> Point {
+        match self {
+            Self::Up => Point::unit_up(),
+            Self::Right => Point::unit_right(),
+            Self::Down => Point::unit_down(),
+            Self::Left => Point::unit_left(),
+        }
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+    // Turns are 45 degrees; two of them make a right angle.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+    pub fn to_point(self) -> Point {
+        match self {
+            Self::Up => Point::unit_up(),
+            Self::UpRight => Point::unit_up() + Point::unit_right(),
+            Self::Right => Point::unit_right(),
+            Self::DownRight => Point::unit_down() + Point::unit_right(),
+            Self::Down => Point::unit_down(),
+            Self::DownLeft => Point::unit_down() + Point::unit_left(),
+            Self::Left => Point::unit_left(),
+            Self::UpLeft => Point::unit_up() + Point::unit_left(),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction4 {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Self::Up),
+            '>' | 'E' | 'R' => Ok(Self::Right),
+            'v' | 'S' | 'D' => Ok(Self::Down),
+            '<' | 'W' | 'L' => Ok(Self::Left),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" | "UR" => Ok(Self::UpRight),
+            "SE" | "DR" => Ok(Self::DownRight),
+            "SW" | "DL" => Ok(Self::DownLeft),
+            "NW" | "UL" => Ok(Self::UpLeft),
+            _ => s.parse::<Direction4>().map(Self::from),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Self {
+        Self::ALL[d as usize * 2]
+    }
+}
+
+impl From<Direction4> for Point {
+    fn from(d: Direction4) -> Self {
+        d.to_point()
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(d: Direction8) -> Self {
+        d.to_point()
+    }
+}
+
+macro_rules! impl_point_add {
+    ($dir:ty) => {
+        impl Add<$dir> for Point {
+            type Output = Point;
+            fn add(self, d: $dir) -> Point {
+                self + d.to_point()
+            }
+        }
+
+        impl AddAssign<$dir> for Point {
+            fn add_assign(&mut self, d: $dir) {
+                *self += d.to_point();
+            }
+        }
+    };
+}
+
+impl_point_add!(Direction4);
+impl_point_add!(Direction8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        use Direction4::*;
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.opposite(), Right);
+        assert!(Direction4::iter().all(|d| d.turn_left().turn_right() == d));
+        assert!(Direction4::iter().all(|d| d.to_point() + d.opposite().to_point() == Point::origin()));
+
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left().turn_left(), Direction8::Left);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(Direction8::iter().filter(|d| d.is_diagonal()).count(), 4);
+        assert_eq!(Direction8::from(Down), Direction8::Down);
+    }
+    #[test]
+    fn test_parse_and_move() {
+        for (s, d) in [("^", Direction4::Up), ("v", Direction4::Down), ("W", Direction4::Left), ("R", Direction4::Right)] {
+            assert_eq!(s.parse(), Ok(d));
+        }
+        assert_eq!("x".parse::<Direction4>(), Err(()));
+        assert_eq!("LR".parse::<Direction4>(), Err(()));
+        assert_eq!("NE".parse(), Ok(Direction8::UpRight));
+        assert_eq!("<".parse(), Ok(Direction8::Left));
+
+        let mut p = Point::new(2, 2);
+        p += Direction4::Up;
+        assert_eq!(p + Direction8::DownRight, Point::new(3, 2));
+        assert_eq!(Point::from(Direction8::UpLeft), Point::new(-1, -1));
+    }
+}
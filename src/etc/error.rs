@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    Overflow { day: u8, op: &'static str },
+    RaggedLine { line: usize, expected: usize, found: usize },
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Overflow { day, op } => write!(f, "day {:02}: overflow in `{}`", day, op),
+            AocError::RaggedLine { line, expected, found } => write!(
+                f,
+                "line {}: expected {} cells, found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
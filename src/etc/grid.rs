@@ -5,7 +5,7 @@ use std::slice::Iter;
 
 use num_traits::PrimInt;
 
-use super::Point;
+use super::{AocError, Point};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
@@ -14,6 +14,18 @@ pub struct Grid<T> {
     data: Vec<T>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Whitespace {
+    Skip,
+    Keep,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged {
+    Reject,
+    Pad(char),
+}
+
 pub struct GridEnumerate<'a, T> {
     iter: Enumerate<Iter<'a, T>>,
     mat: &'a Grid<T>,
@@ -24,10 +36,56 @@ impl<T> Grid<T> {
         assert_eq!(width * height, data.len());
         Self { width, height, data }
     }
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self { width, height, data }
+    }
     pub fn map_from_str(s: &str, f: impl Fn(char) -> T) -> Self {
-        let width = s.lines().next().unwrap().len();
-        let data: Vec<_> = s.chars().filter(|&c| !c.is_whitespace()).map(f).collect();
-        Self::from_data(width, data.len() / width, data)
+        Self::try_map_from_str(s, Whitespace::Skip, Ragged::Reject, f)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_map_from_str(
+        s: &str,
+        whitespace: Whitespace,
+        ragged: Ragged,
+        f: impl Fn(char) -> T,
+    ) -> Result<Self, AocError> {
+        // Under Whitespace::Skip, lines left empty are dropped; line numbers in
+        // errors still count them.
+        let mut lines: Vec<(usize, Vec<char>)> = s
+            .lines()
+            .map(|line| -> Vec<char> {
+                match whitespace {
+                    Whitespace::Skip => line.chars().filter(|c| !c.is_whitespace()).collect(),
+                    Whitespace::Keep => line.chars().collect(),
+                }
+            })
+            .enumerate()
+            .map(|(i, line)| (i + 1, line))
+            .filter(|(_, line)| whitespace == Whitespace::Keep || !line.is_empty())
+            .collect();
+        while lines.last().is_some_and(|(_, line)| line.is_empty()) {
+            lines.pop();
+        }
+        let width = match ragged {
+            Ragged::Reject => lines.first().map_or(0, |(_, line)| line.len()),
+            Ragged::Pad(_) => lines.iter().map(|(_, line)| line.len()).max().unwrap_or(0),
+        };
+
+        let mut data = Vec::with_capacity(width * lines.len());
+        for (number, line) in &lines {
+            if line.len() != width && ragged == Ragged::Reject {
+                return Err(AocError::RaggedLine { line: *number, expected: width, found: line.len() });
+            }
+            data.extend(line.iter().map(|&c| f(c)));
+            if let Ragged::Pad(fill) = ragged {
+                data.extend((line.len()..width).map(|_| f(fill)));
+            }
+        }
+        Ok(Self::from_data(width, lines.len(), data))
     }
     pub fn width(&self) -> usize {
         self.width
@@ -50,6 +108,63 @@ impl<T> Grid<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.data.iter()
     }
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "y out of bounds");
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "y out of bounds");
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "x out of bounds");
+        self.data.iter().skip(x).step_by(self.width)
+    }
+    pub fn column_mut(&mut self, x: usize) -> impl Iterator<Item = &mut T> {
+        assert!(x < self.width, "x out of bounds");
+        self.data.iter_mut().skip(x).step_by(self.width)
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.height && b < self.height, "y out of bounds");
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b {
+            return;
+        }
+        let (top, bottom) = self.data.split_at_mut(b * self.width);
+        top[a * self.width..(a + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+    }
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        assert!(a < self.width && b < self.width, "x out of bounds");
+        for row in self.data.chunks_mut(self.width) {
+            row.swap(a, b);
+        }
+    }
+    // Removes row `from` and reinserts it at `to`, shifting the rows between.
+    pub fn move_row(&mut self, from: usize, to: usize) {
+        assert!(from < self.height && to < self.height, "y out of bounds");
+        let w = self.width;
+        if from < to {
+            self.data[from * w..(to + 1) * w].rotate_left(w);
+        } else {
+            self.data[to * w..(from + 1) * w].rotate_right(w);
+        }
+    }
+    pub fn move_column(&mut self, from: usize, to: usize) {
+        assert!(from < self.width && to < self.width, "x out of bounds");
+        for row in self.data.chunks_mut(self.width) {
+            if from < to {
+                row[from..=to].rotate_left(1);
+            } else {
+                row[to..=from].rotate_right(1);
+            }
+        }
+    }
     pub fn enumerate(&self) -> GridEnumerate<'_,T> {
         GridEnumerate {
             iter: self.data.iter().enumerate(),
@@ -79,6 +194,43 @@ impl<T: Copy> Grid<T> {
     }
 }
 
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(self.width - 1 - y, x)].clone())
+    }
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| self[(x, self.height - 1 - y)].clone())
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    // The four rotations of the grid and of its mirror image, without
+    // repeats for shapes that are symmetric.
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip_horizontal()] {
+            let mut current = start;
+            for _ in 0..4 {
+                let next = current.rotate_cw();
+                if !variants.contains(&current) {
+                    variants.push(current);
+                }
+                current = next;
+            }
+        }
+        variants.into_iter()
+    }
+}
+
 impl<T: PartialEq> Grid<T> {
     pub fn find(&self, elem: &T) -> Option<Point> {
         self.enumerate()
@@ -152,3 +304,65 @@ impl<T: Display> Display for Grid<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_and_pad() {
+        let grid = Grid::try_map_from_str("12 \n4\n 7 8\n", Whitespace::Keep, Ragged::Pad('.'), |c| c).unwrap();
+        assert_eq!(grid.to_string(), "12 .\n4...\n 7 8\n");
+    }
+    #[test]
+    fn test_reject_ragged() {
+        let result = Grid::try_map_from_str("###\n###\n##\n", Whitespace::Skip, Ragged::Reject, |c| c);
+        assert_eq!(result, Err(AocError::RaggedLine { line: 3, expected: 3, found: 2 }));
+    }
+    #[test]
+    fn test_skip_blank_lines() {
+        let grid = Grid::from_str("ab\n\ncd\n  \n");
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+        let result = Grid::try_map_from_str("##\n\n#\n", Whitespace::Skip, Ragged::Reject, |c| c);
+        assert_eq!(result, Err(AocError::RaggedLine { line: 3, expected: 2, found: 1 }));
+    }
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::from_str("abc\ndef\n");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        grid.swap_rows(0, 1);
+        grid.swap_columns(0, 2);
+        assert_eq!(grid.to_string(), "fed\ncba\n");
+        grid.move_column(0, 2);
+        grid.row_mut(0)[0] = 'x';
+        assert_eq!(grid.to_string(), "xdf\nbac\n");
+        grid.move_row(1, 0);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [['b', 'a', 'c'], ['x', 'd', 'f']]);
+        assert_eq!(Grid::new(0, 3, 'a').rows().count(), 3);
+    }
+    #[test]
+    #[should_panic(expected = "y out of bounds")]
+    fn test_swap_rows_out_of_bounds() {
+        Grid::from_str("ab\ncd\n").swap_rows(0, 2);
+    }
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::from_str("abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+    #[test]
+    fn test_symmetries() {
+        assert_eq!(Grid::from_str("##.\n#..\n#..\n").symmetries().count(), 8);
+        assert_eq!(Grid::from_str("###\n.#.\n.#.\n").symmetries().count(), 4);
+        assert_eq!(Grid::from_str("ab\n").symmetries().count(), 4);
+        assert_eq!(Grid::from_str("#.#\n###\n#.#\n").symmetries().count(), 2);
+    }
+}
@@ -0,0 +1,117 @@
+use std::ops::{Index, IndexMut};
+
+use super::Point3;
+
+// Dense 3D grid stored x-fastest, then y, then z.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid3<T> {
+    pub fn from_fn(width: usize, height: usize, depth: usize, f: impl Fn(usize, usize, usize) -> T) -> Self {
+        let data = (0..depth)
+            .flat_map(|z| (0..height).flat_map(move |y| (0..width).map(move |x| (x, y, z))))
+            .map(|(x, y, z)| f(x, y, z))
+            .collect();
+        Self { width, height, depth, data }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    pub fn is_in_bounds(&self, p: Point3) -> bool {
+        p.x >= 0
+            && p.y >= 0
+            && p.z >= 0
+            && (p.x as usize) < self.width
+            && (p.y as usize) < self.height
+            && (p.z as usize) < self.depth
+    }
+    pub fn get(&self, p: Point3) -> Option<&T> {
+        self.is_in_bounds(p).then(|| &self.data[self.index(p)])
+    }
+    pub fn get_mut(&mut self, p: Point3) -> Option<&mut T> {
+        if self.is_in_bounds(p) {
+            let i = self.index(p);
+            Some(&mut self.data[i])
+        } else {
+            None
+        }
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.data.iter().enumerate().map(|(i, v)| (self.coords(i), v))
+    }
+    pub fn neighbors_in_bounds(&self, p: Point3) -> impl Iterator<Item = Point3> + '_ {
+        p.neighbors().into_iter().filter(|&n| self.is_in_bounds(n))
+    }
+    pub fn neighbors_diagonal_in_bounds(&self, p: Point3) -> impl Iterator<Item = Point3> + '_ {
+        p.neighbors_diagonal().into_iter().filter(|&n| self.is_in_bounds(n))
+    }
+    fn index(&self, p: Point3) -> usize {
+        assert!(self.is_in_bounds(p), "point out of bounds: {:?}", p);
+        (p.z as usize * self.height + p.y as usize) * self.width + p.x as usize
+    }
+    fn coords(&self, index: usize) -> Point3 {
+        let x = index % self.width;
+        let y = index / self.width % self.height;
+        let z = index / (self.width * self.height);
+        Point3::new(x as isize, y as isize, z as isize)
+    }
+}
+
+impl<T: Clone> Grid3<T> {
+    pub fn new(width: usize, height: usize, depth: usize, default: T) -> Self {
+        Self { width, height, depth, data: vec![default; width * height * depth] }
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, p: Point3) -> &Self::Output {
+        &self.data[Grid3::index(self, p)]
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    fn index_mut(&mut self, p: Point3) -> &mut Self::Output {
+        let i = Grid3::index(self, p);
+        &mut self.data[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexing() {
+        let grid = Grid3::from_fn(4, 3, 2, |x, y, z| x + 10 * y + 100 * z);
+        assert_eq!(grid[Point3::new(3, 2, 1)], 123);
+        assert_eq!(grid.get(Point3::new(4, 0, 0)), None);
+        assert_eq!(grid.get(Point3::new(0, 0, -1)), None);
+        assert!(grid.enumerate().all(|(p, &v)| v == (p.x + 10 * p.y + 100 * p.z) as usize));
+    }
+    #[test]
+    fn test_neighbors() {
+        let mut grid = Grid3::new(3, 3, 3, 0);
+        grid[Point3::new(1, 1, 1)] = 7;
+        assert_eq!(grid.neighbors_in_bounds(Point3::origin()).count(), 3);
+        assert_eq!(grid.neighbors_diagonal_in_bounds(Point3::origin()).count(), 7);
+        assert_eq!(grid.neighbors_diagonal_in_bounds(Point3::new(1, 1, 1)).count(), 26);
+        *grid.get_mut(Point3::new(2, 2, 2)).unwrap() = 1;
+        assert_eq!(grid.iter().sum::<i32>(), 8);
+    }
+}
@@ -0,0 +1,249 @@
+use std::fs::write;
+use std::io;
+
+use super::{Grid, Point};
+use super::ray::line;
+
+pub type Rgb = [u8; 3];
+
+// A grid rendered to one color per cell, drawn `scale` pixels wide, with
+// optional paths drawn through the cell centers on top.
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    scale: usize,
+    cells: Vec<Rgb>,
+    paths: Vec<(Vec<Point>, Rgb)>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, color: impl Fn(&T) -> Rgb) -> Self {
+        assert!(scale > 0, "scale must be at least 1");
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            scale,
+            cells: grid.iter().map(color).collect(),
+            paths: Vec::new(),
+        }
+    }
+    pub fn with_path(mut self, path: &[Point], color: Rgb) -> Self {
+        self.paths.push((path.to_vec(), color));
+        self
+    }
+    pub fn pixel_width(&self) -> usize {
+        self.width * self.scale
+    }
+    pub fn pixel_height(&self) -> usize {
+        self.height * self.scale
+    }
+    // Writes PPM, PNG or SVG depending on the file extension.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        match path.rsplit_once('.').map(|(_, ext)| ext) {
+            Some("ppm") => write(path, self.to_ppm()),
+            Some("png") => write(path, self.to_png()),
+            Some("svg") => write(path, self.to_svg()),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unknown image format: {}", path))),
+        }
+    }
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.pixel_width(), self.pixel_height()).into_bytes();
+        out.extend(self.pixels().iter().flatten());
+        out
+    }
+    pub fn to_png(&self) -> Vec<u8> {
+        let (w, h) = (self.pixel_width(), self.pixel_height());
+        let pixels = self.pixels();
+        let mut raw = Vec::with_capacity(h * (3 * w + 1));
+        for row in pixels.chunks(w.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((w as u32).to_be_bytes());
+        header.extend((h as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+    pub fn to_svg(&self) -> String {
+        let s = self.scale;
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+            self.pixel_width(),
+            self.pixel_height()
+        );
+        for (y, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let run = row[x..].iter().take_while(|&&c| c == row[x]).count();
+                out.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    x * s,
+                    y * s,
+                    run * s,
+                    s,
+                    hex(row[x])
+                ));
+                x += run;
+            }
+        }
+        for (path, color) in &self.paths {
+            let points: Vec<String> = path
+                .iter()
+                .map(|p| format!("{},{}", p.x as f64 * s as f64 + s as f64 / 2.0, p.y as f64 * s as f64 + s as f64 / 2.0))
+                .collect();
+            out.push_str(&format!(
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                points.join(" "),
+                hex(*color),
+                self.line_width()
+            ));
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+    fn line_width(&self) -> usize {
+        (self.scale / 4).max(1)
+    }
+    fn pixels(&self) -> Vec<Rgb> {
+        let (w, h, s) = (self.pixel_width(), self.pixel_height(), self.scale);
+        let mut pixels = vec![[0; 3]; w * h];
+        for (i, px) in pixels.iter_mut().enumerate() {
+            let (x, y) = (i % w / s, i / w / s);
+            *px = self.cells[y * self.width + x];
+        }
+
+        let half = s as isize / 2;
+        let radius = self.line_width() as isize / 2;
+        for (path, color) in &self.paths {
+            let centers: Vec<Point> = path.iter().map(|p| *p * s as isize + Point::new(half, half)).collect();
+            let segments = centers.windows(2).map(|w| (w[0], w[1]));
+            let single = centers.first().filter(|_| centers.len() == 1).map(|&p| (p, p));
+            for (a, b) in segments.chain(single) {
+                for c in line(a, b) {
+                    for y in c.y - radius..=c.y + radius {
+                        for x in c.x - radius..=c.x + radius {
+                            if x >= 0 && y >= 0 && (x as usize) < w && (y as usize) < h {
+                                pixels[y as usize * w + x as usize] = *color;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        pixels
+    }
+}
+
+fn hex([r, g, b]: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+// zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::from_str("#.\n.#\n");
+        Image::from_grid(&grid, 2, |&c| if c == '#' { [255, 0, 0] } else { [0, 0, 0] })
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+    #[test]
+    fn test_ppm() {
+        let ppm = image().to_ppm();
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 4 * 4 * 3);
+        assert_eq!(&ppm[header.len()..header.len() + 9], [255, 0, 0, 255, 0, 0, 0, 0, 0]);
+    }
+    #[test]
+    fn test_png_layout() {
+        let png = image().with_path(&[Point::new(0, 0), Point::new(1, 1)], [0, 255, 0]).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 4, 0, 0, 0, 4]);
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        // zlib header, one final stored block of 4 rows * (1 + 4 * 3) bytes, adler32
+        assert_eq!(idat_len, 2 + 5 + 4 * 13 + 4);
+        assert_eq!(&png[png.len() - 12..], [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]);
+    }
+    #[test]
+    fn test_path_overlay() {
+        let pixels = image().with_path(&[Point::new(0, 0), Point::new(1, 1)], [0, 255, 0]).pixels();
+        assert_eq!(pixels[4 + 1], [0, 255, 0]);
+        assert_eq!(pixels[2 * 4 + 2], [0, 255, 0]);
+        assert_eq!(pixels[3], [0, 0, 0]);
+    }
+    #[test]
+    fn test_svg() {
+        let svg = image().with_path(&[Point::new(0, 0), Point::new(1, 0)], [0, 0, 255]).to_svg();
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"2\" height=\"2\" fill=\"#000000\"/>"));
+        assert!(svg.contains("<polyline points=\"1,1 3,1\" fill=\"none\" stroke=\"#0000ff\""));
+    }
+}
@@ -1,8 +1,42 @@
 pub mod point;
+pub mod point3;
+pub mod bigint;
+pub mod direction;
 pub mod grid;
+pub mod grid3;
+pub mod rect;
+pub mod polygon;
+pub mod compress;
+pub mod subgrid;
+pub mod sparse_grid;
+pub mod bit_grid;
+pub mod ray;
+pub mod path;
+pub mod region;
+pub mod automaton;
+pub mod image;
+pub mod animate;
 pub mod solution;
+pub mod error;
+pub mod checked;
+pub mod ocr;
 pub mod utils;
-pub use point::Point;
+pub use point::{Coord, Neighborhood, Orientation, Point, Point2, SignedCoord};
+pub use point3::Point3;
+pub use bigint::{BigInt, BigUint};
+pub use direction::{Direction4, Direction8};
 pub use grid::Grid;
+pub use grid3::Grid3;
+pub use rect::Rect;
+pub use polygon::Polygon;
+pub use compress::{Axis, Compressed, PrefixSum};
+pub use subgrid::{SubGrid, SubGridMut};
+pub use sparse_grid::SparseGrid;
+pub use bit_grid::BitGrid;
+pub use ray::{line, Ray};
+pub use automaton::Automaton;
+pub use image::{Image, Rgb};
 pub use solution::Solution;
+pub use error::AocError;
+pub use ocr::read_letters;
 pub use utils::{gcd, lcm};
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use super::{Grid, Solution};
+
+// Glyphs of the two fonts used in puzzle outputs, '#' for lit cells.
+const FONT_4X6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const FONT_6X10: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+pub trait Pixel {
+    fn is_lit(&self) -> bool;
+}
+
+impl Pixel for bool {
+    fn is_lit(&self) -> bool {
+        *self
+    }
+}
+
+impl Pixel for char {
+    fn is_lit(&self) -> bool {
+        matches!(self, '#' | '█')
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    UnknownFont { height: usize },
+    // `text` has '?' in place of each glyph listed in `glyphs`.
+    Unrecognized { text: String, glyphs: Vec<(usize, String)> },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "no lit cells to read"),
+            OcrError::UnknownFont { height } => write!(f, "no font with letters {height} cells tall"),
+            OcrError::Unrecognized { text, glyphs } => {
+                write!(f, "unrecognized glyphs in \"{text}\":")?;
+                for (i, glyph) in glyphs {
+                    write!(f, "\n#{i}:\n{glyph}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Letters are split on blank columns and read left to right; blank rows and
+// columns around the text are ignored.
+pub fn read_letters<T: Pixel>(grid: &Grid<T>) -> Result<Solution, OcrError> {
+    let lit_rows: Vec<usize> = (0..grid.height())
+        .filter(|&y| (0..grid.width()).any(|x| grid[(x, y)].is_lit()))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Empty);
+    };
+    match bottom - top + 1 {
+        6 => read_font(grid, top, FONT_4X6),
+        10 => read_font(grid, top, FONT_6X10),
+        height => Err(OcrError::UnknownFont { height }),
+    }
+}
+
+// Font glyph without its blank edge columns, in the same layout as the
+// pieces cut from the grid.
+fn trim_glyph(rows: &[&str]) -> String {
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+    let width = rows[0].len();
+    let (Some(left), Some(right)) = ((0..width).find(|&x| lit(x)), (0..width).rfind(|&x| lit(x))) else {
+        return String::new();
+    };
+    let rows: Vec<&str> = rows.iter().map(|row| &row[left..=right]).collect();
+    rows.join("\n")
+}
+
+fn read_font<T: Pixel, const H: usize>(
+    grid: &Grid<T>,
+    top: usize,
+    font: &[(char, [&str; H])],
+) -> Result<Solution, OcrError> {
+    let lit = |x: usize| (top..top + H).any(|y| grid[(x, y)].is_lit());
+    let mut pieces = Vec::new();
+    let mut x = 0;
+    while x < grid.width() {
+        if lit(x) {
+            let start = x;
+            while x < grid.width() && lit(x) {
+                x += 1;
+            }
+            pieces.push(start..x);
+        }
+        x += 1;
+    }
+    let glyphs = pieces.into_iter().map(|columns| {
+        let rows: Vec<String> = (top..top + H)
+            .map(|y| columns.clone().map(|x| if grid[(x, y)].is_lit() { '#' } else { '.' }).collect())
+            .collect();
+        rows.join("\n")
+    });
+
+    let mut text = String::new();
+    let mut unrecognized = Vec::new();
+    for (i, glyph) in glyphs.enumerate() {
+        let letter = font.iter().find(|(_, rows)| trim_glyph(rows) == glyph).map(|&(c, _)| c);
+        match letter {
+            Some(c) => text.push(c),
+            None => {
+                text.push('?');
+                unrecognized.push((i, glyph));
+            }
+        }
+    }
+    if unrecognized.is_empty() {
+        Ok(Solution::Str(text))
+    } else {
+        Err(OcrError::Unrecognized { text, glyphs: unrecognized })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_font() {
+        let grid = Grid::from_str(
+            "\
+..........................
+#..#.####.###..#....###...
+#..#.#....#..#.#....#..#..
+####.###..#..#.#....#..#..
+#..#.#....###..#....###...
+#..#.#....#.#..#....#.....
+#..#.####.#..#.####.#.....
+",
+        );
+        assert_eq!(read_letters(&grid), Ok(Solution::from("HERLP")));
+    }
+    #[test]
+    fn test_large_font_bool() {
+        let rows = FONT_6X10[0].1.iter().zip(FONT_6X10[13].1.iter());
+        let text: String = rows.map(|(a, x)| format!("{a}..{x}\n")).collect();
+        let grid = Grid::map_from_str(&text, |c| c == '#');
+        assert_eq!(read_letters(&grid), Ok(Solution::from("AX")));
+    }
+    #[test]
+    fn test_offset_text() {
+        let grid = Grid::from_str(
+            "\
+.......
+..####.
+..#....
+..###..
+..#....
+..#....
+..####.
+.......
+",
+        );
+        assert_eq!(read_letters(&grid), Ok(Solution::from("E")));
+    }
+    #[test]
+    fn test_leading_blank_column() {
+        let grid = Grid::from_str(
+            "\
+.###.#..#
+..#..#..#
+..#..####
+..#..#..#
+..#..#..#
+.###.#..#
+",
+        );
+        assert_eq!(read_letters(&grid), Ok(Solution::from("IH")));
+    }
+    #[test]
+    fn test_unrecognized() {
+        let grid = Grid::from_str(
+            "\
+####.#..#
+#....#..#
+###..#..#
+#....####
+#.......#
+####....#
+",
+        );
+        match read_letters(&grid) {
+            Err(OcrError::Unrecognized { text, glyphs }) => {
+                assert_eq!(text, "E?");
+                assert_eq!(glyphs.len(), 1);
+                assert_eq!(glyphs[0].0, 1);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
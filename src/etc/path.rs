@@ -0,0 +1,188 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Grid, Neighborhood, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: u64,
+    pub points: Vec<Point>,
+}
+
+// Distance in moves from `start` to every reachable cell; `passable` is
+// checked on the cell being entered.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: Point,
+    neighborhood: Neighborhood,
+    passable: impl Fn(&T) -> bool,
+) -> Grid<Option<usize>> {
+    bfs_tree(grid, start, None, neighborhood, passable).0
+}
+
+pub fn bfs_path<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    neighborhood: Neighborhood,
+    passable: impl Fn(&T) -> bool,
+) -> Option<Path> {
+    let (dist, prev) = bfs_tree(grid, start, Some(goal), neighborhood, passable);
+    let cost = dist.get(goal).copied().flatten()? as u64;
+    Some(Path { cost, points: walk_back(&prev, goal) })
+}
+
+// `cost(from, to)` is the price of one move, or None if it is not allowed.
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    neighborhood: Neighborhood,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+) -> Option<Path> {
+    best_first(grid, start, goal, neighborhood, cost, |_| 0)
+}
+
+// The heuristic is the Manhattan distance for 4-neighborhoods and the
+// Chebyshev distance for 8-neighborhoods, so it stays admissible as long as
+// every move costs at least 1.
+pub fn astar<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    neighborhood: Neighborhood,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+) -> Option<Path> {
+    let heuristic = |p: Point| match neighborhood {
+        Neighborhood::Four => p.manhattan_distance(&goal) as u64,
+        Neighborhood::Eight => p.chebyshev_distance(&goal) as u64,
+    };
+    best_first(grid, start, goal, neighborhood, cost, heuristic)
+}
+
+fn bfs_tree<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Option<Point>,
+    neighborhood: Neighborhood,
+    passable: impl Fn(&T) -> bool,
+) -> (Grid<Option<usize>>, Grid<Option<Point>>) {
+    let mut dist = Grid::new(grid.width(), grid.height(), None);
+    let mut prev = Grid::new(grid.width(), grid.height(), None);
+    let mut queue = VecDeque::new();
+    dist[start] = Some(0);
+    queue.push_back((start, 0));
+
+    while let Some((pos, d)) = queue.pop_front() {
+        if Some(pos) == goal {
+            break;
+        }
+        for next in neighborhood.neighbors_in_bounds(pos, grid) {
+            if dist[next].is_none() && passable(&grid[next]) {
+                dist[next] = Some(d + 1);
+                prev[next] = Some(pos);
+                queue.push_back((next, d + 1));
+            }
+        }
+    }
+    (dist, prev)
+}
+
+fn best_first<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    neighborhood: Neighborhood,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+    heuristic: impl Fn(Point) -> u64,
+) -> Option<Path> {
+    let mut dist = Grid::new(grid.width(), grid.height(), u64::MAX);
+    let mut prev = Grid::new(grid.width(), grid.height(), None);
+    let mut heap = BinaryHeap::new();
+    dist[start] = 0;
+    heap.push(Reverse((heuristic(start), 0, start.y, start.x)));
+
+    while let Some(Reverse((_, d, y, x))) = heap.pop() {
+        let pos = Point::new(x, y);
+        if pos == goal {
+            return Some(Path { cost: d, points: walk_back(&prev, goal) });
+        }
+        if d > dist[pos] {
+            continue;
+        }
+        for next in neighborhood.neighbors_in_bounds(pos, grid) {
+            let Some(step) = cost(pos, next) else {
+                continue;
+            };
+            let nd = d + step;
+            if nd < dist[next] {
+                dist[next] = nd;
+                prev[next] = Some(pos);
+                heap.push(Reverse((nd + heuristic(next), nd, next.y, next.x)));
+            }
+        }
+    }
+    None
+}
+
+fn walk_back(prev: &Grid<Option<Point>>, goal: Point) -> Vec<Point> {
+    let mut points = vec![goal];
+    while let Some(p) = prev[*points.last().unwrap()] {
+        points.push(p);
+    }
+    points.reverse();
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Grid<char> {
+        Grid::from_str(
+            "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E
+",
+        )
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let start = grid.find(&'S').unwrap();
+        let goal = grid.find(&'E').unwrap();
+        let dist = bfs(&grid, start, Neighborhood::Four, |&c| c != '#');
+        assert_eq!(dist[goal], Some(15));
+        assert_eq!(dist[(1, 1)], None);
+
+        let path = bfs_path(&grid, start, goal, Neighborhood::Four, |&c| c != '#').unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.points.len(), 16);
+        assert_eq!((path.points[0], path.points[15]), (start, goal));
+        assert!(path.points.windows(2).all(|w| w[0].manhattan_distance(&w[1]) == 1));
+
+        let diagonal = bfs_path(&grid, start, goal, Neighborhood::Eight, |&c| c != '#').unwrap();
+        assert!(diagonal.cost < path.cost);
+    }
+    #[test]
+    fn test_weighted() {
+        let grid = Grid::from_str("11111\n19991\n11191\n99191\n11111\n");
+        let start = Point::new(0, 0);
+        let goal = Point::new(4, 4);
+        let cost = |_, to: Point| grid[to].to_digit(10).map(|d| d as u64);
+        let a = dijkstra(&grid, start, goal, Neighborhood::Four, cost).unwrap();
+        let b = astar(&grid, start, goal, Neighborhood::Four, cost).unwrap();
+        assert_eq!(a.cost, 8);
+        assert_eq!(b.cost, a.cost);
+        assert_eq!(b.points.len(), 9);
+
+        let walls = |_, to: Point| (grid[to] == '1').then_some(1);
+        let eight = astar(&grid, start, goal, Neighborhood::Eight, walls).unwrap();
+        assert_eq!(eight.cost, dijkstra(&grid, start, goal, Neighborhood::Eight, walls).unwrap().cost);
+        assert!(dijkstra(&grid, start, Point::new(2, 1), Neighborhood::Four, walls).is_none());
+    }
+}
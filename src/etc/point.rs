@@ -1,66 +1,135 @@
-use std::fmt::Display;
+use std::cmp::Ordering;
 use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, MulAssign, Neg};
-use num_traits::PrimInt;
+use num_traits::{cast, PrimInt, Signed};
 use super::Grid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Point {
-    pub const fn new(x: isize, y: isize) -> Self {
+// Grid coordinates. Other widths suit memory-heavy puzzles (i32) or huge
+// coordinates (i64, i128).
+pub type Point = Point2<isize>;
+
+// Turn direction as seen on screen, with y pointing down as in grids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    Four,
+    Eight,
+}
+
+// Integer coordinate types, with the constants the const constructors need.
+pub trait Coord: PrimInt {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+// Coordinates that can point up and left of the origin.
+pub trait SignedCoord: Coord + Signed {
+    const MINUS_ONE: Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_coord {
+    ($($t:ty),*) => {
+        $(
+            impl SignedCoord for $t {
+                const MINUS_ONE: Self = -1;
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_signed_coord!(i8, i16, i32, i64, i128, isize);
+
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+}
+
+// Moving up or left from 0 underflows for unsigned coordinates, like the
+// subtraction it is.
+impl<T: Coord> Point2<T> {
     pub const fn origin() -> Self {
-        Self { x: 0, y: 0 }
-    }
-    pub const fn unit_up() -> Self {
-        Self { x: 0, y: -1 }
+        Self::new(T::ZERO, T::ZERO)
     }
     pub const fn unit_down() -> Self {
-        Self { x: 0, y: 1 }
-    }
-    pub const fn unit_left() -> Self {
-        Self { x: -1, y: 0 } 
+        Self::new(T::ZERO, T::ONE)
     }
     pub const fn unit_right() -> Self {
-        Self { x: 1, y: 0 }
+        Self::new(T::ONE, T::ZERO)
+    }
+    // None if either coordinate does not fit in T.
+    pub fn try_new<I: PrimInt>(x: I, y: I) -> Option<Self> {
+        Some(Self::new(cast(x)?, cast(y)?))
+    }
+    pub fn cast<U: Coord>(self) -> Option<Point2<U>> {
+        Point2::try_new(self.x, self.y)
     }
     pub fn up(&self) -> Self {
-        self + Self::unit_up()
+        Self::new(self.x, self.y - T::ONE)
     }
     pub fn down(&self) -> Self {
         self + Self::unit_down()
     }
     pub fn left(&self) -> Self {
-        self + Self::unit_left()
+        Self::new(self.x - T::ONE, self.y)
     }
     pub fn right(&self) -> Self {
         self + Self::unit_right()
     }
+    // Panics if the distance does not fit in usize, which only 128-bit
+    // coordinates can reach.
     pub fn manhattan_distance(&self, other: &Self) -> usize {
-        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+        let d = abs_diff(self.x, other.x) + abs_diff(self.y, other.y);
+        d.to_usize().expect("manhattan distance does not fit in usize")
     }
     pub fn euclidean_distance(&self, other: &Self) -> f64 {
-        let dx = (self.x - other.x) as f64;
-        let dy = (self.y - other.y) as f64;
+        let dx = abs_diff(self.x, other.x).to_f64().unwrap();
+        let dy = abs_diff(self.y, other.y).to_f64().unwrap();
         (dx * dx + dy * dy).sqrt()
     }
-    pub fn neighbors(&self) -> Vec<Self> {
-        vec![
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+    pub fn neighbors(&self) -> [Self; 4] {
+        [
             self.up(),
             self.down(),
             self.left(),
             self.right(),
         ]
     }
-    pub fn neighbors_in_bounds<'a, T>(&self, grid: &'a Grid<T>) -> impl IntoIterator<Item = Self> + 'a {
-        self.neighbors().into_iter().filter(move |p| grid.is_in_bounds(*p))
-    }
-    pub fn neighbors_diagonal(&self) -> Vec<Self> {
-        vec![
+    pub fn neighbors_diagonal(&self) -> [Self; 8] {
+        [
             self.up(),
             self.down(),
             self.left(),
@@ -71,141 +140,303 @@ impl Point {
             self.down().right(),
         ]
     }
-    pub fn neighbors_diagonal_in_bounds<'a, T>(&self, grid: &'a Grid<T>) -> impl IntoIterator<Item = Self> + 'a {
-        self.neighbors_diagonal().into_iter().filter(move |p| grid.is_in_bounds(*p))
-    }
 }
 
-impl<I: PrimInt + Display> From<(I, I)> for Point {
-    fn from((x, y): (I, I)) -> Self {
-        let x = x.to_isize().unwrap_or_else(
-            || panic!("Value out of range for isize")
-        );
-        let y = y.to_isize().unwrap_or_else(
-            || panic!("Value out of range for isize")
-        );
-        Self::new(x, y)
+impl<T: SignedCoord> Point2<T> {
+    pub const fn unit_up() -> Self {
+        Self::new(T::ZERO, T::MINUS_ONE)
     }
-}
-
-impl From<Point> for (isize, isize) {
-    fn from(point: Point) -> Self {
-        (point.x, point.y)
+    pub const fn unit_left() -> Self {
+        Self::new(T::MINUS_ONE, T::ZERO)
+    }
+    // z component of the 3D cross product; positive when `other` is
+    // clockwise from `self` on screen.
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+    // Quarter turns as seen on screen: up becomes right under `rotate_cw`.
+    pub fn rotate_cw(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+    pub fn rotate_ccw(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+    pub fn rotate_cw_around(&self, pivot: &Self) -> Self {
+        pivot + (self - pivot).rotate_cw()
+    }
+    pub fn rotate_ccw_around(&self, pivot: &Self) -> Self {
+        pivot + (self - pivot).rotate_ccw()
+    }
+    // Which way the path a -> b -> c turns at b.
+    pub fn orientation(a: &Self, b: &Self, c: &Self) -> Orientation {
+        match (b - a).cross(&(c - a)).cmp(&T::zero()) {
+            Ordering::Greater => Orientation::Clockwise,
+            Ordering::Less => Orientation::CounterClockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+    pub fn is_collinear(a: &Self, b: &Self, c: &Self) -> bool {
+        Self::orientation(a, b, c) == Orientation::Collinear
+    }
+    // Orders `a` and `b` by the clockwise angle from straight up around
+    // `self`, nearer points first on ties. `self` itself sorts first.
+    pub fn cmp_angle(&self, a: &Self, b: &Self) -> Ordering {
+        let half = |d: Self| {
+            if d == Self::origin() {
+                0
+            } else if d.x > T::zero() || (d.x == T::zero() && d.y < T::zero()) {
+                1
+            } else {
+                2
+            }
+        };
+        let (da, db) = (a - self, b - self);
+        half(da)
+            .cmp(&half(db))
+            .then_with(|| T::zero().cmp(&da.cross(&db)))
+            .then_with(|| da.manhattan_distance(&Self::origin()).cmp(&db.manhattan_distance(&Self::origin())))
+    }
+    pub fn sort_by_angle(&self, points: &mut [Self]) {
+        points.sort_by(|a, b| self.cmp_angle(a, b));
     }
 }
 
-impl Add<Point> for Point {
-    type Output = Self;
-    fn add(self, other: Point) -> Self {
-        Self::new(self.x + other.x, self.y + other.y)
+impl Point {
+    pub fn neighbors_in_bounds<'a, T>(&self, grid: &'a Grid<T>) -> impl IntoIterator<Item = Self> + use<'a, T> {
+        self.neighbors().into_iter().filter(move |p| grid.is_in_bounds(*p))
     }
-}
-
-impl Add<Point> for &Point {
-    type Output = Point;
-    fn add(self, other: Point) -> Point {
-        Point::new(self.x + other.x, self.y + other.y)
+    pub fn neighbors_diagonal_in_bounds<'a, T>(&self, grid: &'a Grid<T>) -> impl IntoIterator<Item = Self> + use<'a, T> {
+        self.neighbors_diagonal().into_iter().filter(move |p| grid.is_in_bounds(*p))
     }
 }
 
-impl Add<&Point> for Point {
-    type Output = Point;
-    fn add(self, other: &Point) -> Point {
-        Point::new(self.x + other.x, self.y + other.y)
+impl Neighborhood {
+    pub fn neighbors_in_bounds<'a, T>(self, p: Point, grid: &'a Grid<T>) -> impl Iterator<Item = Point> + 'a {
+        match self {
+            Neighborhood::Four => Neighbors::Four(p.neighbors_in_bounds(grid).into_iter()),
+            Neighborhood::Eight => Neighbors::Eight(p.neighbors_diagonal_in_bounds(grid).into_iter()),
+        }
     }
 }
 
-impl Add<&Point> for &Point {
-    type Output = Point;
-    fn add(self, other: &Point) -> Point {
-        Point::new(self.x + other.x, self.y + other.y)
-    }
+// Either neighbor iterator behind one type, without boxing.
+enum Neighbors<A, B> {
+    Four(A),
+    Eight(B),
 }
 
-impl AddAssign<Point> for Point {
-    fn add_assign(&mut self, other: Point) {
-        *self = *self + other;
+impl<A: Iterator<Item = Point>, B: Iterator<Item = Point>> Iterator for Neighbors<A, B> {
+    type Item = Point;
+    fn next(&mut self) -> Option<Point> {
+        match self {
+            Neighbors::Four(iter) => iter.next(),
+            Neighbors::Eight(iter) => iter.next(),
+        }
     }
 }
 
-impl Sub<Point> for Point {
-    type Output = Self;
-    fn sub(self, other: Point) -> Self {
-        Self::new(self.x - other.x, self.y - other.y)
+impl<T: Coord, I: PrimInt> TryFrom<(I, I)> for Point2<T> {
+    type Error = ();
+    fn try_from((x, y): (I, I)) -> Result<Self, Self::Error> {
+        Self::try_new(x, y).ok_or(())
     }
 }
 
-impl Sub<Point> for &Point {
-    type Output = Point;
-    fn sub(self, other: Point) -> Point {
-        Point::new(self.x - other.x, self.y - other.y)
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
     }
 }
 
-impl Sub<&Point> for Point {
-    type Output = Point;
-    fn sub(self, other: &Point) -> Point {
-        Point::new(self.x - other.x, self.y - other.y)
-    }
-}
+macro_rules! impl_point_op {
+    ($trait_:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: PrimInt> $trait_<Point2<T>> for Point2<T> {
+            type Output = Self;
+            fn $method(self, other: Point2<T>) -> Self {
+                Self::new(self.x $op other.x, self.y $op other.y)
+            }
+        }
 
-impl Sub<&Point> for &Point {
-    type Output = Point;
-    fn sub(self, other: &Point) -> Point {
-        Point::new(self.x - other.x, self.y - other.y)
-    }
-}
+        impl<T: PrimInt> $trait_<Point2<T>> for &Point2<T> {
+            type Output = Point2<T>;
+            fn $method(self, other: Point2<T>) -> Point2<T> {
+                *self $op other
+            }
+        }
 
-impl SubAssign<Point> for Point {
-    fn sub_assign(&mut self, other: Point) {
-        *self = *self - other;
-    }
+        impl<T: PrimInt> $trait_<&Point2<T>> for Point2<T> {
+            type Output = Point2<T>;
+            fn $method(self, other: &Point2<T>) -> Point2<T> {
+                self $op *other
+            }
+        }
+
+        impl<T: PrimInt> $trait_<&Point2<T>> for &Point2<T> {
+            type Output = Point2<T>;
+            fn $method(self, other: &Point2<T>) -> Point2<T> {
+                *self $op *other
+            }
+        }
+
+        impl<T: PrimInt> $assign_trait<Point2<T>> for Point2<T> {
+            fn $assign_method(&mut self, other: Point2<T>) {
+                *self = *self $op other;
+            }
+        }
+    };
 }
 
-impl Mul<isize> for Point {
+impl_point_op!(Add, add, AddAssign, add_assign, +);
+impl_point_op!(Sub, sub, SubAssign, sub_assign, -);
+
+impl<T: PrimInt> Mul<T> for Point2<T> {
     type Output = Self;
-    fn mul(self, scalar: isize) -> Self {
+    fn mul(self, scalar: T) -> Self {
         Self::new(self.x * scalar, self.y * scalar)
     }
 }
 
-impl Mul<isize> for &Point {
-    type Output = Point;
-    fn mul(self, scalar: isize) -> Point {
-        Point::new(self.x * scalar, self.y * scalar)
+impl<T: PrimInt> Mul<T> for &Point2<T> {
+    type Output = Point2<T>;
+    fn mul(self, scalar: T) -> Point2<T> {
+        *self * scalar
     }
 }
 
-impl Mul<Point> for isize {
-    type Output = Point;
-    fn mul(self, point: Point) -> Point {
-        Point::new(point.x * self, point.y * self)
+impl<T: PrimInt> MulAssign<T> for Point2<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
     }
 }
 
-impl Mul<&Point> for isize {
-    type Output = Point;
-    fn mul(self, point: &Point) -> Point {
-        Point::new(point.x * self, point.y * self)
-    }
-}
+// `scalar * point` has to be implemented per scalar type.
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Point2<$t>> for $t {
+                type Output = Point2<$t>;
+                fn mul(self, point: Point2<$t>) -> Point2<$t> {
+                    point * self
+                }
+            }
 
-impl MulAssign<isize> for Point {
-    fn mul_assign(&mut self, scalar: isize) {
-        *self = *self * scalar;
-    }
+            impl Mul<&Point2<$t>> for $t {
+                type Output = Point2<$t>;
+                fn mul(self, point: &Point2<$t>) -> Point2<$t> {
+                    *point * self
+                }
+            }
+        )*
+    };
 }
 
-impl Neg for Point {
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize);
+
+impl<T: PrimInt + Signed> Neg for Point2<T> {
     type Output = Self;
     fn neg(self) -> Self {
         Self::new(-self.x, -self.y)
     }
 }
 
-impl Neg for &Point {
-    type Output = Point;
-    fn neg(self) -> Point {
-        Point::new(-self.x, -self.y)
+impl<T: PrimInt + Signed> Neg for &Point2<T> {
+    type Output = Point2<T>;
+    fn neg(self) -> Point2<T> {
+        -*self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Point::try_from((3u64, 4u64)), Ok(Point::new(3, 4)));
+        assert_eq!(Point::try_from((u64::MAX, 0)), Err(()));
+        assert_eq!(Point2::<i32>::try_new(1i64 << 40, 0), None);
+        assert_eq!(Point::new(-5, 7).cast::<i8>(), Some(Point2::new(-5i8, 7)));
+        assert_eq!(Point::new(300, 0).cast::<i8>(), None);
+        assert_eq!(<(isize, isize)>::from(Point::new(1, 2)), (1, 2));
+    }
+    #[test]
+    fn test_const_constructors() {
+        const CORNERS: [Point; 2] = [Point::origin(), Point::new(3, 4)];
+        const UP: Point2<i32> = Point2::unit_up();
+        assert_eq!(CORNERS[0].manhattan_distance(&CORNERS[1]), 7usize);
+        assert_eq!(UP + Point2::unit_down(), Point2::origin());
+    }
+    #[test]
+    fn test_other_widths() {
+        let p = Point2::new(3i32, -4);
+        assert_eq!(2 * p - p, p);
+        assert_eq!(-p * 2, Point2::new(-6, 8));
+        assert_eq!(p.manhattan_distance(&Point2::origin()), 7);
+        assert_eq!(p.euclidean_distance(&Point2::origin()), 5.0);
+        let big = Point2::new(i128::MAX / 2, 0);
+        assert_eq!(big.right().x, i128::MAX / 2 + 1);
+        assert_eq!(p.neighbors_diagonal().iter().filter(|n| n.manhattan_distance(&p) == 2).count(), 4);
+    }
+    #[test]
+    fn test_unsigned() {
+        const START: Point2<u16> = Point2::origin();
+        let p = Point2::new(3u32, 5);
+        assert_eq!(p.manhattan_distance(&Point2::new(7, 1)), 8);
+        assert_eq!(p.chebyshev_distance(&Point2::new(7, 1)), 4);
+        assert_eq!(p.up().left(), Point2::new(2, 4));
+        assert_eq!(START.right().down(), Point2::new(1, 1));
+        assert_eq!(Point2::<u32>::try_from((-1i64, 0)), Err(()));
+        assert_eq!(Point::new(2, 3).cast::<usize>(), Some(Point2::new(2usize, 3)));
+    }
+    #[test]
+    fn test_vector_ops() {
+        let (a, b) = (Point::new(3, -4), Point::new(2, 5));
+        assert_eq!(a.dot(&b), -14);
+        assert_eq!(a.cross(&b), 23);
+        assert_eq!(a.chebyshev_distance(&b), 9);
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+        assert_eq!(Point::unit_up().rotate_cw(), Point::unit_right());
+        assert_eq!(Point::unit_up().rotate_ccw(), Point::unit_left());
+        assert_eq!(a.rotate_cw().rotate_ccw(), a);
+        assert_eq!(Point::new(5, 2).rotate_cw_around(&Point::new(4, 2)), Point::new(4, 3));
+        assert_eq!(Point::new(5, 2).rotate_ccw_around(&Point::new(4, 2)), Point::new(4, 1));
+    }
+    #[test]
+    fn test_orientation() {
+        let (a, b) = (Point::origin(), Point::new(2, 0));
+        assert_eq!(Point::orientation(&a, &b, &Point::new(3, 1)), Orientation::Clockwise);
+        assert_eq!(Point::orientation(&a, &b, &Point::new(3, -1)), Orientation::CounterClockwise);
+        assert!(Point::is_collinear(&a, &b, &Point::new(-5, 0)));
+    }
+    #[test]
+    fn test_sort_by_angle() {
+        let center = Point::new(1, 1);
+        let mut points = [
+            Point::new(0, 1),
+            Point::new(1, 3),
+            Point::new(2, 0),
+            Point::new(1, -2),
+            Point::new(1, 0),
+            Point::new(0, 0),
+            Point::new(2, 1),
+            Point::new(1, 1),
+        ];
+        center.sort_by_angle(&mut points);
+        assert_eq!(
+            points,
+            [
+                Point::new(1, 1),
+                Point::new(1, 0),
+                Point::new(1, -2),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(1, 3),
+                Point::new(0, 1),
+                Point::new(0, 0),
+            ]
+        );
     }
 }
@@ -0,0 +1,160 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+    pub const fn origin() -> Self {
+        Self { x: 0, y: 0, z: 0 }
+    }
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
+    }
+    // Kept squared so comparisons stay exact in integers.
+    pub fn squared_distance(&self, other: &Self) -> u64 {
+        let d = self - other;
+        (d.x * d.x + d.y * d.y + d.z * d.z) as u64
+    }
+    pub fn neighbors(&self) -> [Self; 6] {
+        [
+            self + Self::new(-1, 0, 0),
+            self + Self::new(1, 0, 0),
+            self + Self::new(0, -1, 0),
+            self + Self::new(0, 1, 0),
+            self + Self::new(0, 0, -1),
+            self + Self::new(0, 0, 1),
+        ]
+    }
+    pub fn neighbors_diagonal(&self) -> [Self; 26] {
+        let mut neighbors = [*self; 26];
+        let offsets = (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| Self::new(x, y, z))))
+            .filter(|&d| d != Self::origin());
+        for (n, d) in neighbors.iter_mut().zip(offsets) {
+            *n += d;
+        }
+        neighbors
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',').map(|p| p.trim().parse::<isize>());
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(x)), Some(Ok(y)), Some(Ok(z)), None) => Ok(Self::new(x, y, z)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+macro_rules! impl_point3_op {
+    ($trait_:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait_<Point3> for Point3 {
+            type Output = Point3;
+            fn $method(self, other: Point3) -> Point3 {
+                Point3::new(self.x $op other.x, self.y $op other.y, self.z $op other.z)
+            }
+        }
+
+        impl $trait_<Point3> for &Point3 {
+            type Output = Point3;
+            fn $method(self, other: Point3) -> Point3 {
+                *self $op other
+            }
+        }
+
+        impl $trait_<&Point3> for Point3 {
+            type Output = Point3;
+            fn $method(self, other: &Point3) -> Point3 {
+                self $op *other
+            }
+        }
+
+        impl $trait_<&Point3> for &Point3 {
+            type Output = Point3;
+            fn $method(self, other: &Point3) -> Point3 {
+                *self $op *other
+            }
+        }
+
+        impl $assign_trait<Point3> for Point3 {
+            fn $assign_method(&mut self, other: Point3) {
+                *self = *self $op other;
+            }
+        }
+    };
+}
+
+impl_point3_op!(Add, add, AddAssign, add_assign, +);
+impl_point3_op!(Sub, sub, SubAssign, sub_assign, -);
+
+impl Mul<isize> for Point3 {
+    type Output = Self;
+    fn mul(self, scalar: isize) -> Self {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Mul<Point3> for isize {
+    type Output = Point3;
+    fn mul(self, point: Point3) -> Point3 {
+        point * self
+    }
+}
+
+impl MulAssign<isize> for Point3 {
+    fn mul_assign(&mut self, scalar: isize) {
+        *self = *self * scalar;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_arithmetic_and_distance() {
+        let a = Point3::new(162, 817, 812);
+        let b = Point3::new(425, 690, 689);
+        assert_eq!(a + b - b, a);
+        assert_eq!(-a * 2, Point3::new(-324, -1634, -1624));
+        assert_eq!(a.manhattan_distance(&b), 263 + 127 + 123);
+        assert_eq!(a.squared_distance(&b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!("162,817,812".parse(), Ok(a));
+        assert_eq!("1,2".parse::<Point3>(), Err(()));
+        assert_eq!("1,2,3,4".parse::<Point3>(), Err(()));
+    }
+    #[test]
+    fn test_neighbors() {
+        let p = Point3::new(1, -1, 4);
+        assert!(p.neighbors().iter().all(|n| n.manhattan_distance(&p) == 1));
+        let all: HashSet<Point3> = p.neighbors_diagonal().into_iter().collect();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&p));
+        assert!(p.neighbors().iter().all(|n| all.contains(n)));
+    }
+}
@@ -0,0 +1,174 @@
+use super::{gcd, Point, Rect};
+
+// Simple closed polygon through lattice points; the last vertex connects
+// back to the first. Containment counts the boundary as inside.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+    rectilinear: bool,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        assert!(vertices.len() >= 3, "polygon needs at least 3 vertices");
+        let mut polygon = Self { vertices, rectilinear: false };
+        let rectilinear = polygon.edges().all(|(a, b)| a.x == b.x || a.y == b.y);
+        polygon.rectilinear = rectilinear;
+        polygon
+    }
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+    pub fn is_rectilinear(&self) -> bool {
+        self.rectilinear
+    }
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+    // Shoelace formula; twice the area keeps it an integer.
+    pub fn double_area(&self) -> u64 {
+        self.edges().map(|(a, b)| a.cross(&b)).sum::<isize>().unsigned_abs() as u64
+    }
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+    // Lattice points on the edges.
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).unsigned_abs() as u64, (b.y - a.y).unsigned_abs() as u64))
+            .sum()
+    }
+    // Lattice points strictly inside, by Pick's theorem A = I + B/2 - 1.
+    pub fn interior_points(&self) -> u64 {
+        (self.double_area() + 2 - self.boundary_points()) / 2
+    }
+    // Lattice points inside or on the boundary.
+    pub fn lattice_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        self.contains_doubled(p * 2)
+    }
+    // True if every point of `rect`, taken as the area between its corner
+    // points, is inside or on the polygon. Only for rectilinear polygons.
+    pub fn contains_rect(&self, rect: &Rect) -> bool {
+        assert!(self.rectilinear, "contains_rect needs a rectilinear polygon");
+        let (min, max) = (rect.min, rect.max);
+        if min.x == max.x || min.y == max.y {
+            return self.contains_segment(min, max);
+        }
+        // With no edge through the open interior, the interior is all on one
+        // side of the boundary and its center decides which.
+        self.edges().all(|(a, b)| !rect.overlaps_interior(&Rect::new(a, b)))
+            && self.contains_doubled(min + max)
+    }
+    // Axis-aligned segment: the boundary can only be crossed at vertex
+    // coordinates, so testing those and the midpoints between them is exact.
+    fn contains_segment(&self, a: Point, b: Point) -> bool {
+        let mut cuts: Vec<Point> = if a.y == b.y {
+            self.vertices.iter().filter(|v| a.x < v.x && v.x < b.x).map(|v| Point::new(v.x, a.y)).collect()
+        } else {
+            self.vertices.iter().filter(|v| a.y < v.y && v.y < b.y).map(|v| Point::new(a.x, v.y)).collect()
+        };
+        cuts.push(a);
+        cuts.push(b);
+        cuts.sort_unstable_by_key(|p| (p.x, p.y));
+        cuts.dedup();
+        cuts.iter().all(|&p| self.contains_doubled(p * 2))
+            && cuts.windows(2).all(|w| self.contains_doubled(w[0] + w[1]))
+    }
+    // Point-in-polygon for a point given in doubled coordinates, so that
+    // half-integer points such as cell centers stay exact.
+    fn contains_doubled(&self, p: Point) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (a, b) = (a * 2, b * 2);
+            let cross = (b - a).cross(&(p - a));
+            if cross == 0 && Rect::new(a, b).contains(p) {
+                return true;
+            }
+            if (a.y > p.y) != (b.y > p.y) && (cross > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The red tiles of the day 9 example.
+    fn red_tiles() -> Polygon {
+        Polygon::new(vec![
+            Point::new(7, 1),
+            Point::new(11, 1),
+            Point::new(11, 7),
+            Point::new(9, 7),
+            Point::new(9, 5),
+            Point::new(2, 5),
+            Point::new(2, 3),
+            Point::new(7, 3),
+        ])
+    }
+
+    #[test]
+    fn test_area_and_pick() {
+        let square = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)]);
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)]);
+        assert!(!triangle.is_rectilinear());
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+
+        let tiles = red_tiles();
+        assert_eq!(tiles.lattice_points(), 46);
+    }
+    #[test]
+    fn test_contains() {
+        let tiles = red_tiles();
+        assert!(tiles.contains(Point::new(7, 1)));
+        assert!(tiles.contains(Point::new(2, 4)));
+        assert!(tiles.contains(Point::new(8, 4)));
+        assert!(tiles.contains(Point::new(10, 6)));
+        assert!(!tiles.contains(Point::new(3, 2)));
+        assert!(!tiles.contains(Point::new(8, 6)));
+        assert!(!tiles.contains(Point::new(12, 4)));
+    }
+    #[test]
+    fn test_contains_rect() {
+        let tiles = red_tiles();
+        assert!(tiles.contains_rect(&Rect::new(Point::new(9, 5), Point::new(2, 3))));
+        assert!(tiles.contains_rect(&Rect::new(Point::new(7, 3), Point::new(11, 1))));
+        assert!(!tiles.contains_rect(&Rect::new(Point::new(2, 5), Point::new(11, 1))));
+        assert!(!tiles.contains_rect(&Rect::new(Point::new(9, 7), Point::new(2, 5))));
+        // Segments along and across the boundary.
+        assert!(tiles.contains_rect(&Rect::new(Point::new(2, 5), Point::new(9, 5))));
+        assert!(tiles.contains_rect(&Rect::new(Point::new(11, 1), Point::new(11, 7))));
+        assert!(!tiles.contains_rect(&Rect::new(Point::new(2, 3), Point::new(2, 7))));
+        assert!(!tiles.contains_rect(&Rect::new(Point::new(8, 7), Point::new(11, 7))));
+
+        // A U shape with a notch cut into its top side.
+        let u = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 2),
+            Point::new(4, 2),
+            Point::new(4, 0),
+            Point::new(6, 0),
+            Point::new(6, 4),
+            Point::new(0, 4),
+        ]);
+        assert!(!u.contains_rect(&Rect::new(Point::new(0, 0), Point::new(6, 4))));
+        assert!(u.contains_rect(&Rect::new(Point::new(0, 2), Point::new(6, 4))));
+        assert!(!u.contains_rect(&Rect::new(Point::new(1, 1), Point::new(5, 1))));
+        assert!(u.contains_rect(&Rect::new(Point::new(0, 0), Point::new(2, 0))));
+    }
+}
@@ -0,0 +1,152 @@
+use super::{Grid, Point};
+
+// Unit steps in the same order as `Point::neighbors_diagonal`.
+const DIRECTIONS: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, -1),
+    Point::new(1, -1),
+    Point::new(-1, 1),
+    Point::new(1, 1),
+];
+
+// Cells met walking from `pos` by `step`, not including `pos` itself, until
+// the walk leaves the grid.
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Point,
+    step: Point,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == Point::origin() {
+            return None;
+        }
+        self.pos += self.step;
+        self.grid.get(self.pos).map(|v| (self.pos, v))
+    }
+}
+
+// Points on the Bresenham line from `start` to `end`, both included.
+#[derive(Debug, Clone)]
+pub struct Line {
+    pos: Point,
+    end: Point,
+    dx: isize,
+    dy: isize,
+    sx: isize,
+    sy: isize,
+    err: isize,
+    done: bool,
+}
+
+pub fn line(start: Point, end: Point) -> Line {
+    let (dx, dy) = ((end.x - start.x).abs(), -(end.y - start.y).abs());
+    Line {
+        pos: start,
+        end,
+        dx,
+        dy,
+        sx: (end.x - start.x).signum(),
+        sy: (end.y - start.y).signum(),
+        err: dx + dy,
+        done: false,
+    }
+}
+
+impl Iterator for Line {
+    type Item = Point;
+    fn next(&mut self) -> Option<Point> {
+        if self.done {
+            return None;
+        }
+        let current = self.pos;
+        if current == self.end {
+            self.done = true;
+            return Some(current);
+        }
+        let e2 = 2 * self.err;
+        if e2 >= self.dy {
+            self.err += self.dy;
+            self.pos.x += self.sx;
+        }
+        if e2 <= self.dx {
+            self.err += self.dx;
+            self.pos.y += self.sy;
+        }
+        Some(current)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn ray(&self, from: Point, step: Point) -> Ray<'_, T> {
+        Ray { grid: self, pos: from, step }
+    }
+    // Like `ray`, but stops after the first cell for which `blocks` holds.
+    pub fn ray_until<'a>(
+        &'a self,
+        from: Point,
+        step: Point,
+        blocks: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let mut blocked = false;
+        self.ray(from, step).take_while(move |(_, v)| {
+            let keep = !blocked;
+            blocked = blocks(v);
+            keep
+        })
+    }
+    // First cell along `step` for which `hit` holds.
+    pub fn cast(&self, from: Point, step: Point, hit: impl Fn(&T) -> bool) -> Option<Point> {
+        self.ray(from, step).find(|(_, v)| hit(v)).map(|(p, _)| p)
+    }
+    // First cell in each of the 8 directions for which `visible` holds, in
+    // `Point::neighbors_diagonal` order.
+    pub fn first_visible(&self, from: Point, visible: impl Fn(&T) -> bool) -> [Option<Point>; 8] {
+        DIRECTIONS.map(|step| self.cast(from, step, &visible))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rays() {
+        let grid = Grid::from_str("#....\n.....\n..L.#\n.....\n#...#\n");
+        let from = Point::new(2, 2);
+        let right: Vec<Point> = grid.ray(from, Point::new(1, 0)).map(|(p, _)| p).collect();
+        assert_eq!(right, [Point::new(3, 2), Point::new(4, 2)]);
+        assert_eq!(grid.ray(from, Point::new(0, -2)).count(), 1);
+        assert_eq!(grid.ray(from, Point::origin()).count(), 0);
+
+        let until: Vec<char> = grid.ray_until(Point::new(0, 2), Point::new(1, 0), |&c| c == 'L').map(|(_, &c)| c).collect();
+        assert_eq!(until, ['.', 'L']);
+
+        let seen = grid.first_visible(from, |&c| c == '#');
+        assert_eq!(seen[0], None);
+        assert_eq!(seen[3], Some(Point::new(4, 2)));
+        assert_eq!(seen[4], Some(Point::new(0, 0)));
+        assert_eq!(seen[6], Some(Point::new(0, 4)));
+        assert_eq!(seen[7], Some(Point::new(4, 4)));
+        assert_eq!(seen.iter().flatten().count(), 4);
+    }
+    #[test]
+    fn test_line() {
+        let points: Vec<Point> = line(Point::new(0, 0), Point::new(4, 2)).collect();
+        assert_eq!(
+            points,
+            [Point::new(0, 0), Point::new(1, 1), Point::new(2, 1), Point::new(3, 2), Point::new(4, 2)]
+        );
+        let back: Vec<Point> = line(Point::new(4, 2), Point::new(0, 0)).collect();
+        assert_eq!(back.len(), 5);
+        assert_eq!((back[0], back[4]), (Point::new(4, 2), Point::new(0, 0)));
+        assert_eq!(line(Point::new(3, -1), Point::new(3, -1)).collect::<Vec<_>>(), [Point::new(3, -1)]);
+        assert_eq!(line(Point::new(0, 0), Point::new(0, -3)).count(), 4);
+    }
+}
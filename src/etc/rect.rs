@@ -0,0 +1,105 @@
+use super::{checked, Point};
+
+// Axis-aligned rectangle spanning `min` to `max`, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+    pub fn width(&self) -> u64 {
+        (self.max.x - self.min.x + 1) as u64
+    }
+    pub fn height(&self) -> u64 {
+        (self.max.y - self.min.y + 1) as u64
+    }
+    // Number of cells covered, counting both corners.
+    pub fn area(&self) -> u64 {
+        checked::mul(self.width(), self.height(), "rectangle area")
+    }
+    // Geometric area between the corners taken as points.
+    pub fn exclusive_area(&self) -> u64 {
+        checked::mul(self.width() - 1, self.height() - 1, "rectangle area")
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+    // True if the two share at least one cell.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+    // True if the open interiors overlap; touching along an edge does not count.
+    pub fn overlaps_interior(&self, other: &Self) -> bool {
+        self.min.x < other.max.x
+            && self.max.x > other.min.x
+            && self.min.y < other.max.y
+            && self.max.y > other.min.y
+    }
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        })
+    }
+    // Smallest rectangle covering both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_and_contains() {
+        let r = Rect::new(Point::new(11, 7), Point::new(2, 3));
+        assert_eq!((r.min, r.max), (Point::new(2, 3), Point::new(11, 7)));
+        assert_eq!(r.area(), 50);
+        assert_eq!(r.exclusive_area(), 36);
+        assert!(r.contains(Point::new(2, 7)));
+        assert!(!r.contains(Point::new(12, 5)));
+        assert!(r.contains_rect(&Rect::new(Point::new(3, 4), Point::new(11, 4))));
+        assert_eq!(Rect::new(Point::new(1, 1), Point::new(1, 1)).area(), 1);
+    }
+    #[test]
+    fn test_overlaps() {
+        let a = Rect::new(Point::new(0, 0), Point::new(4, 4));
+        let edge = Rect::new(Point::new(4, 1), Point::new(6, 3));
+        let apart = Rect::new(Point::new(5, 0), Point::new(6, 1));
+        assert!(a.intersects(&edge));
+        assert!(!a.overlaps_interior(&edge));
+        assert!(!a.intersects(&apart));
+        assert!(a.overlaps_interior(&Rect::new(Point::new(3, 3), Point::new(9, 9))));
+        assert_eq!(a.intersection(&edge), Some(Rect::new(Point::new(4, 1), Point::new(4, 3))));
+        assert_eq!(a.intersection(&apart), None);
+        assert_eq!(a.union(&apart), Rect::new(Point::new(0, 0), Point::new(6, 4)));
+    }
+    #[test]
+    fn test_points() {
+        let r = Rect::new(Point::new(-1, 2), Point::new(0, 3));
+        let points: Vec<Point> = r.points().collect();
+        assert_eq!(points, [Point::new(-1, 2), Point::new(0, 2), Point::new(-1, 3), Point::new(0, 3)]);
+        assert_eq!(points.len() as u64, r.area());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use super::{Grid, Neighborhood, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub label: usize,
+    // First cell of the component in reading order.
+    pub start: Point,
+    pub size: usize,
+    pub min: Point,
+    pub max: Point,
+    pub perimeter: usize,
+    pub sides: usize,
+}
+
+// Every cell connected to `start` through cells matching `pred`, in the order
+// they were reached.
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    start: Point,
+    neighborhood: Neighborhood,
+    pred: impl Fn(&T) -> bool,
+) -> Vec<Point> {
+    if !grid.get(start).is_some_and(&pred) {
+        return Vec::new();
+    }
+    let mut seen = Grid::new(grid.width(), grid.height(), false);
+    let mut filled = vec![start];
+    let mut queue = VecDeque::from([start]);
+    seen[start] = true;
+    while let Some(pos) = queue.pop_front() {
+        for next in neighborhood.neighbors_in_bounds(pos, grid) {
+            if !seen[next] && pred(&grid[next]) {
+                seen[next] = true;
+                filled.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    filled
+}
+
+// Groups connected cells holding equal values. Labels are indexes into the
+// returned components, numbered in reading order of each component's start.
+// Perimeter and sides are measured on cell edges, whatever the neighborhood.
+pub fn label_components<T: PartialEq>(
+    grid: &Grid<T>,
+    neighborhood: Neighborhood,
+) -> (Grid<usize>, Vec<Component>) {
+    let mut labels = Grid::new(grid.width(), grid.height(), usize::MAX);
+    let mut components = Vec::new();
+    for (start, value) in grid.enumerate() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+        let label = components.len();
+        for p in flood_fill(grid, start, neighborhood, |v| v == value) {
+            labels[p] = label;
+        }
+        components.push(Component {
+            label,
+            start,
+            size: 0,
+            min: start,
+            max: start,
+            perimeter: 0,
+            sides: 0,
+        });
+    }
+
+    let same = |p: Point, label: usize| labels.get(p) == Some(&label);
+    for (pos, &label) in labels.enumerate() {
+        let c = &mut components[label];
+        c.size += 1;
+        c.min = Point::new(c.min.x.min(pos.x), c.min.y.min(pos.y));
+        c.max = Point::new(c.max.x.max(pos.x), c.max.y.max(pos.y));
+        c.perimeter += pos.neighbors().into_iter().filter(|&n| !same(n, label)).count();
+        for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+            let horizontal = same(pos + Point::new(dx, 0), label);
+            let vertical = same(pos + Point::new(0, dy), label);
+            let diagonal = same(pos + Point::new(dx, dy), label);
+            if (!horizontal && !vertical) || (horizontal && vertical && !diagonal) {
+                c.sides += 1;
+            }
+        }
+    }
+    (labels, components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::from_str("..#\n.#.\n#..\n");
+        assert_eq!(flood_fill(&grid, Point::new(0, 0), Neighborhood::Four, |&c| c == '.').len(), 3);
+        assert_eq!(flood_fill(&grid, Point::new(2, 0), Neighborhood::Eight, |&c| c == '#').len(), 3);
+        assert!(flood_fill(&grid, Point::new(2, 0), Neighborhood::Four, |&c| c == '.').is_empty());
+    }
+    #[test]
+    fn test_label_components() {
+        let grid = Grid::from_str("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let (labels, components) = label_components(&grid, Neighborhood::Four);
+        assert_eq!(components.len(), 5);
+        assert_eq!(labels[(1, 2)], labels[(0, 1)]);
+        let stats: Vec<_> = components.iter().map(|c| (c.size, c.perimeter, c.sides)).collect();
+        assert_eq!(stats, [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]);
+        assert_eq!((components[2].min, components[2].max), (Point::new(2, 1), Point::new(3, 3)));
+    }
+    #[test]
+    fn test_sides_with_hole() {
+        let grid = Grid::from_str("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n");
+        let (_, components) = label_components(&grid, Neighborhood::Four);
+        assert_eq!(components[0].sides, 12);
+        let grid = Grid::from_str("OOO\nOXO\nOOO\n");
+        let (_, components) = label_components(&grid, Neighborhood::Four);
+        assert_eq!((components[0].perimeter, components[0].sides), (16, 8));
+    }
+}
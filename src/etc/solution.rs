@@ -1,7 +1,11 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter, Result };
+use std::str::FromStr;
+use super::BigInt;
 use Solution::*;
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum Solution {
     I8(i8),
     I16(i16),
@@ -15,7 +19,10 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    Big(BigInt),
     Str(String),
+    Multiline(String),
+    Unsolved,
 }
 
 impl Display for Solution {
@@ -33,7 +40,78 @@ impl Display for Solution {
             U64(v) => v.fmt(f),
             U128(v) => v.fmt(f),
             Usize(v) => v.fmt(f),
+            Big(v) => v.fmt(f),
             Str(v) => v.fmt(f),
+            Multiline(v) => v.fmt(f),
+            Unsolved => "unsolved".fmt(f),
+        }
+    }
+}
+
+impl Solution {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Unsolved)
+    }
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Multiline(_))
+    }
+    fn numeric(&self) -> Option<BigInt> {
+        match self {
+            I8(v) => Some(BigInt::from(*v)),
+            I16(v) => Some(BigInt::from(*v)),
+            I32(v) => Some(BigInt::from(*v)),
+            I64(v) => Some(BigInt::from(*v)),
+            I128(v) => Some(BigInt::from(*v)),
+            Isize(v) => Some(BigInt::from(*v)),
+            U8(v) => Some(BigInt::from(*v)),
+            U16(v) => Some(BigInt::from(*v)),
+            U32(v) => Some(BigInt::from(*v)),
+            U64(v) => Some(BigInt::from(*v)),
+            U128(v) => Some(BigInt::from(*v)),
+            Usize(v) => Some(BigInt::from(*v)),
+            Big(v) => Some(v.clone()),
+            Str(_) | Multiline(_) | Unsolved => None,
+        }
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// Numbers are ordered by value. Strings, multiline answers and Unsolved only
+// compare equal to an identical value of the same variant and are otherwise
+// unordered, so a wrong string answer is never "too high" or "too low".
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Str(a), Str(b)) | (Multiline(a), Multiline(b)) => (a == b).then_some(Ordering::Equal),
+            (Unsolved, Unsolved) => Some(Ordering::Equal),
+            _ => Some(self.numeric()?.cmp(&other.numeric()?)),
+        }
+    }
+}
+
+// Anything that is not a number is kept as a string, so parsing never fails.
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(v) = s.parse::<u64>() {
+            Ok(U64(v))
+        } else if let Ok(v) = s.parse::<i64>() {
+            Ok(I64(v))
+        } else if let Ok(v) = s.parse::<u128>() {
+            Ok(U128(v))
+        } else if let Ok(v) = s.parse::<i128>() {
+            Ok(I128(v))
+        } else if let Ok(v) = s.parse::<BigInt>() {
+            Ok(Big(v))
+        } else {
+            Ok(Str(s.to_owned()))
         }
     }
 }
@@ -60,6 +138,7 @@ impl_from!(u32, U32);
 impl_from!(u64, U64);
 impl_from!(u128, U128);
 impl_from!(usize, Usize);
+impl_from!(BigInt, Big);
 impl_from!(String, Str);
 
 impl From<&str> for Solution {
@@ -67,3 +146,56 @@ impl From<&str> for Solution {
         Self::Str(sol.to_owned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq_across_variants() {
+        assert_eq!(U64(5), Usize(5));
+        assert_eq!(I8(-3), I128(-3));
+        assert_eq!(U128(u128::MAX), U128(u128::MAX));
+        assert_ne!(I64(-1), U128(u128::MAX));
+        assert_ne!(U64(5), Str("5".to_owned()));
+        assert_ne!(U64(0), Unsolved);
+    }
+    #[test]
+    fn test_ordering() {
+        assert!(U64(6) > I32(5));
+        assert!(I64(-7) < U8(0));
+        assert!(U128(u128::MAX) > I128(i128::MAX));
+        assert!(Big(BigInt::from(u128::MAX) * BigInt::from(2u8)) > U128(u128::MAX));
+        assert_eq!(Big(BigInt::from(-3)), I8(-3));
+        assert_eq!(Str("a".to_owned()).partial_cmp(&U8(1)), None);
+    }
+    #[test]
+    fn test_eq_agrees_with_partial_cmp() {
+        let values = [
+            U8(1),
+            Big(BigInt::from(1)),
+            I64(-1),
+            Str("x".to_owned()),
+            Str("y".to_owned()),
+            Multiline("a\nb".to_owned()),
+            Multiline("a\nc".to_owned()),
+            Unsolved,
+        ];
+        for a in &values {
+            for b in &values {
+                assert_eq!(a == b, a.partial_cmp(b) == Some(Ordering::Equal), "{:?} vs {:?}", a, b);
+            }
+        }
+        assert_eq!(Str("x".to_owned()).partial_cmp(&Str("x".to_owned())), Some(Ordering::Equal));
+        assert_eq!(Str("x".to_owned()).partial_cmp(&Str("y".to_owned())), None);
+        assert_eq!(Unsolved.partial_cmp(&Unsolved), Some(Ordering::Equal));
+    }
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("1227775554".parse(), Ok(U64(1227775554))));
+        assert!(matches!(" -42\n".parse(), Ok(I64(-42))));
+        assert!(matches!("18446744073709551616".parse(), Ok(U128(_))));
+        assert!(matches!("-340282366920938463463374607431768211457".parse(), Ok(Big(_))));
+        assert!(matches!("EFEHJK".parse(), Ok(Str(s)) if s == "EFEHJK"));
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, Point};
+
+// Cells keyed by position with no fixed size. `min` and `max` are the
+// inclusive corners of the occupied bounding box while the grid is non-empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    min: Point,
+    max: Point,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self { cells: HashMap::new(), min: Point::origin(), max: Point::origin() }
+    }
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        (!self.is_empty()).then_some((self.min, self.max))
+    }
+    pub fn width(&self) -> usize {
+        self.bounds().map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+    pub fn height(&self) -> usize {
+        self.bounds().map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        if self.is_empty() {
+            (self.min, self.max) = (p, p);
+        } else {
+            self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+            self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+        }
+        self.cells.insert(p, value)
+    }
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        let removed = self.cells.remove(&p)?;
+        if p.x == self.min.x || p.y == self.min.y || p.x == self.max.x || p.y == self.max.y {
+            self.recompute_bounds();
+        }
+        Some(removed)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+    fn recompute_bounds(&mut self) {
+        let mut points = self.cells.keys();
+        let Some(&first) = points.next() else {
+            (self.min, self.max) = (Point::origin(), Point::origin());
+            return;
+        };
+        (self.min, self.max) = points.fold((first, first), |(min, max), p| {
+            (Point::new(min.x.min(p.x), min.y.min(p.y)), Point::new(max.x.max(p.x), max.y.max(p.y)))
+        });
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self {
+        let mut sparse = Self::new();
+        for (p, v) in grid.enumerate().filter(|(_, v)| keep(v)) {
+            sparse.insert(p, v.clone());
+        }
+        sparse
+    }
+    // Dense copy of the bounding box with `fill` in empty cells, and the
+    // position of the grid's (0, 0) cell in sparse coordinates.
+    pub fn to_grid(&self, fill: T) -> (Grid<T>, Point) {
+        let grid = Grid::from_fn(self.width(), self.height(), |x, y| {
+            let p = self.min + Point::new(x as isize, y as isize);
+            self.get(p).cloned().unwrap_or_else(|| fill.clone())
+        });
+        (grid, self.min)
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T>
+where
+    T: Clone,
+{
+    fn from(grid: Grid<T>) -> Self {
+        Self::from_grid(&grid, |_| true)
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut sparse = Self::new();
+        for (p, v) in iter {
+            sparse.insert(p, v);
+        }
+        sparse
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p).unwrap_or_else(|| panic!("no cell at {:?}", p))
+    }
+}
+
+impl<T> IndexMut<Point> for SparseGrid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p).unwrap_or_else(|| panic!("no cell at {:?}", p))
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(v) => write!(f, "{}", v)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_and_display() {
+        let mut sparse = SparseGrid::new();
+        sparse.insert(Point::new(-2, 5), '#');
+        sparse.insert(Point::new(1, 3), '@');
+        sparse.insert(Point::new(0, 4), '#');
+        assert_eq!(sparse.bounds(), Some((Point::new(-2, 3), Point::new(1, 5))));
+        assert_eq!(sparse.to_string(), "...@\n..#.\n#...\n");
+        assert_eq!(sparse[Point::new(1, 3)], '@');
+
+        sparse.remove(Point::new(-2, 5));
+        assert_eq!(sparse.bounds(), Some((Point::new(0, 3), Point::new(1, 4))));
+        sparse.remove(Point::new(1, 3));
+        sparse.remove(Point::new(0, 4));
+        assert_eq!(sparse.bounds(), None);
+        sparse.insert(Point::new(7, 7), 'a');
+        assert_eq!(sparse.bounds(), Some((Point::new(7, 7), Point::new(7, 7))));
+    }
+    #[test]
+    fn test_grid_round_trip() {
+        let grid = Grid::from_str("....\n.@@.\n..@.\n");
+        let sparse = SparseGrid::from_grid(&grid, |&c| c == '@');
+        assert_eq!(sparse.len(), 3);
+        let (dense, origin) = sparse.to_grid('.');
+        assert_eq!(origin, Point::new(1, 1));
+        assert_eq!(dense.to_string(), "@@\n.@\n");
+        assert_eq!(SparseGrid::from(grid.clone()).to_grid('?').0, grid);
+    }
+}
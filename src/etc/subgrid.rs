@@ -0,0 +1,200 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, Point};
+
+// A rectangular region of a grid, addressed with its own coordinates: (0, 0)
+// is the grid cell at `origin`.
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug)]
+pub struct SubGridMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+fn fits<T>(grid: &Grid<T>, origin: Point, width: usize, height: usize) -> bool {
+    origin.x >= 0
+        && origin.y >= 0
+        && origin.x as usize + width <= grid.width()
+        && origin.y as usize + height <= grid.height()
+}
+
+impl<T> Grid<T> {
+    pub fn view(&self) -> SubGrid<'_, T> {
+        self.subgrid(Point::origin(), self.width(), self.height())
+    }
+    pub fn subgrid(&self, origin: Point, width: usize, height: usize) -> SubGrid<'_, T> {
+        self.get_subgrid(origin, width, height)
+            .unwrap_or_else(|| panic!("subgrid {}x{} at {:?} out of bounds", width, height, origin))
+    }
+    pub fn get_subgrid(&self, origin: Point, width: usize, height: usize) -> Option<SubGrid<'_, T>> {
+        fits(self, origin, width, height).then_some(SubGrid { grid: self, origin, width, height })
+    }
+    pub fn subgrid_mut(&mut self, origin: Point, width: usize, height: usize) -> SubGridMut<'_, T> {
+        assert!(
+            fits(self, origin, width, height),
+            "subgrid {}x{} at {:?} out of bounds",
+            width,
+            height,
+            origin
+        );
+        SubGridMut { grid: self, origin, width, height }
+    }
+    // Every `width` x `height` window, left to right then top to bottom.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = SubGrid<'_, T>> {
+        let xs = (self.width() + 1).saturating_sub(width);
+        let ys = (self.height() + 1).saturating_sub(height);
+        (0..ys)
+            .flat_map(move |y| (0..xs).map(move |x| Point::new(x as isize, y as isize)))
+            .map(move |origin| SubGrid { grid: self, origin, width, height })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn paste(&mut self, src: &SubGrid<'_, T>, offset: Point) {
+        let mut dst = self.subgrid_mut(offset, src.width, src.height);
+        for (p, v) in src.enumerate() {
+            dst[p] = v.clone();
+        }
+    }
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+    pub fn is_in_bounds(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+    pub fn get(&self, p: Point) -> Option<&'a T> {
+        self.is_in_bounds(p).then(|| &self.grid[self.origin + p])
+    }
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &'a T)> + use<'a, T> {
+        let (grid, origin, width) = (self.grid, self.origin, self.width);
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+            .map(move |p| (p, &grid[origin + p]))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        self.enumerate().map(|(_, v)| v)
+    }
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| self[Point::new(x as isize, y as isize)].clone())
+    }
+}
+
+impl<T> SubGridMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+    pub fn as_subgrid(&self) -> SubGrid<'_, T> {
+        SubGrid { grid: self.grid, origin: self.origin, width: self.width, height: self.height }
+    }
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.as_subgrid().is_in_bounds(p) {
+            Some(&mut self.grid[self.origin + p])
+        } else {
+            None
+        }
+    }
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self[Point::new(x as isize, y as isize)] = value.clone();
+            }
+        }
+    }
+}
+
+impl<T> Index<Point> for SubGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        assert!(self.is_in_bounds(p), "point out of subgrid bounds: {:?}", p);
+        &self.grid[self.origin + p]
+    }
+}
+
+impl<T> Index<Point> for SubGridMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        assert!(self.as_subgrid().is_in_bounds(p), "point out of subgrid bounds: {:?}", p);
+        &self.grid[self.origin + p]
+    }
+}
+
+impl<T> IndexMut<Point> for SubGridMut<'_, T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        assert!(self.as_subgrid().is_in_bounds(p), "point out of subgrid bounds: {:?}", p);
+        &mut self.grid[self.origin + p]
+    }
+}
+
+impl<T: Display> Display for SubGrid<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", self[Point::new(x as isize, y as isize)])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_views() {
+        let mut grid = Grid::from_str("abcd\nefgh\nijkl\n");
+        let sub = grid.subgrid(Point::new(1, 1), 2, 2);
+        assert_eq!(sub.to_string(), "fg\njk\n");
+        assert_eq!(sub.get(Point::new(1, 0)), Some(&'g'));
+        assert_eq!(sub.get(Point::new(2, 0)), None);
+        assert!(grid.get_subgrid(Point::new(3, 0), 2, 1).is_none());
+
+        grid.subgrid_mut(Point::new(2, 0), 2, 2).fill('.');
+        assert_eq!(grid.to_string(), "ab..\nef..\nijkl\n");
+    }
+    #[test]
+    fn test_windows_and_paste() {
+        let grid = Grid::from_str("abc\ndef\n");
+        let windows: Vec<String> = grid.windows(2, 2).map(|w| w.iter().collect()).collect();
+        assert_eq!(windows, ["abde", "bcef"]);
+        assert_eq!(grid.windows(4, 1).count(), 0);
+
+        let mut target = Grid::new(4, 3, '.');
+        target.paste(&grid.subgrid(Point::new(1, 0), 2, 2), Point::new(2, 1));
+        assert_eq!(target.to_string(), "....\n..bc\n..ef\n");
+    }
+}
@@ -4,10 +4,12 @@
 mod days;
 mod etc;
 
+use etc::checked;
 use etc::solution::Solution;
 use days::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
            day11, day12};
 use std::env;
+use std::fs::read_to_string;
 use std::time::Instant;
 
 pub type SolutionPair = (Solution, Solution);
@@ -17,7 +19,16 @@ fn main() {
     if args.len() < 2 {
         panic!("Please provide the day number as an argument.");
     }
-    let days: Vec<u8> = args[1..]
+    let (flags, days): (Vec<&String>, Vec<&String>) = args[1..].iter().partition(|arg| arg.starts_with("--"));
+    let (mut bench, mut visualize) = (false, false);
+    for flag in flags {
+        match flag.as_str() {
+            "--visualize" => visualize = true,
+            "--bench" => bench = true,
+            _ => panic!("Unknown flag: {}", flag),
+        }
+    }
+    let days: Vec<u8> = days
         .iter()
         .map(|arg| arg.parse::<u8>().expect("Invalid day number"))
         .collect();
@@ -26,20 +37,128 @@ fn main() {
 
     for day in days {
         let func = get_day_solver(day);
+        // Animations play before the timed run so frame delays stay out of
+        // the reported times.
+        if visualize && let Some(animation) = get_day_visualizer(day) {
+            animation();
+        }
 
         let start = Instant::now();
-        let (part1, part2) = func();
+        let result = checked::run(day, func);
         let duration = start.elapsed().as_nanos() as f64 / 1_000_000.0;     
+        let (part1, part2) = match result {
+            Ok(parts) => parts,
+            Err(err) => {
+                println!("\n=== Day {:02} ===", day);
+                println!("  . Error: {err}");
+                continue;
+            }
+        };
+        let expected = read_answers(day);
         println!("\n=== Day {:02} ===", day);
-        println!("  . Part 1: {part1}");
-        println!("  . Part 2: {part2}");
+        print_part(1, &part1, expected.first());
+        print_part(2, &part2, expected.get(1));
         println!("  . Time: {:.4} ms", duration);
+        if bench {
+            let (min, mean, runs) = bench_fn(func);
+            println!("  . Bench: min {:.4} ms, mean {:.4} ms over {} runs", min, mean, runs);
+            if let Some(extra) = get_day_bench(day) {
+                extra();
+            }
+        }
         runtime += duration;
     }
 
     println!("Total time: {:.4} ms", runtime);
 }
 
+// Reruns `func` for about a second, at least 5 and at most 1000 times, and
+// returns the fastest and mean run time in ms with the number of runs.
+pub fn bench_fn<R>(func: impl Fn() -> R) -> (f64, f64, usize) {
+    let start = Instant::now();
+    let mut times = Vec::new();
+    while times.len() < 5 || (times.len() < 1000 && start.elapsed().as_secs_f64() < 1.0) {
+        let run = Instant::now();
+        std::hint::black_box(func());
+        times.push(run.elapsed().as_nanos() as f64 / 1_000_000.0);
+    }
+    let min = times.iter().copied().fold(f64::INFINITY, f64::min);
+    let mean = times.iter().sum::<f64>() / times.len() as f64;
+    (min, mean, times.len())
+}
+
+fn read_answers(day: u8) -> Vec<Solution> {
+    read_to_string(format!("answers/day{:02}.txt", day))
+        .map(|input| parse_answers(&input))
+        .unwrap_or_default()
+}
+
+// One answer per line, in part order. A multiline answer is written on the
+// lines between two lines holding only `"""`.
+fn parse_answers(input: &str) -> Vec<Solution> {
+    let mut answers = Vec::new();
+    let mut lines = input.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == "\"\"\"" {
+            let block: Vec<&str> = lines.by_ref().take_while(|line| line.trim() != "\"\"\"").collect();
+            answers.push(Solution::Multiline(block.join("\n")));
+        } else {
+            let Ok(answer) = line.parse::<Solution>();
+            answers.push(answer);
+        }
+    }
+    answers
+}
+
+fn print_part(part: u8, solution: &Solution, expected: Option<&Solution>) {
+    let verdict = verdict(solution, expected);
+    if solution.is_multiline() {
+        println!("  . Part {part}:{verdict}");
+        for line in solution.to_string().lines() {
+            println!("      {line}");
+        }
+    } else {
+        println!("  . Part {part}: {solution}{verdict}");
+    }
+}
+
+fn verdict(solution: &Solution, expected: Option<&Solution>) -> String {
+    let Some(expected) = expected.filter(|_| solution.is_solved()) else {
+        return String::new();
+    };
+    let correct = match (solution, expected) {
+        (Solution::Multiline(a), Solution::Multiline(b)) => a.trim_end() == b.trim_end(),
+        _ => solution == expected,
+    };
+    if correct {
+        " (correct)".to_string()
+    } else if solution.is_multiline() {
+        " (wrong)".to_string()
+    } else if solution > expected {
+        format!(" (wrong, too high: expected {expected})")
+    } else if solution < expected {
+        format!(" (wrong, too low: expected {expected})")
+    } else {
+        format!(" (wrong: expected {expected})")
+    }
+}
+
+// Extra benchmarks a day runs after its own timing under --bench.
+fn get_day_bench(day: u8) -> Option<fn()> {
+    match day {
+        4 => Some(day04::bench_neighbors),
+        _ => None,
+    }
+}
+
+fn get_day_visualizer(day: u8) -> Option<fn()> {
+    match day {
+        4 => Some(day04::visualize),
+        7 => Some(day07::visualize),
+        _ => None,
+    }
+}
+
 fn get_day_solver(day: u8) -> fn() -> SolutionPair {
     match day {
         1 => day01::solve,
@@ -57,3 +176,31 @@ fn get_day_solver(day: u8) -> fn() -> SolutionPair {
         _ => unimplemented!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("42\n\"\"\"\n#.#\n.#.\n\"\"\"\nEFEHJK\n");
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers[0], Solution::from(42u64));
+        assert_eq!(answers[1], Solution::Multiline("#.#\n.#.".to_owned()));
+        assert_eq!(answers[2], Solution::from("EFEHJK"));
+    }
+    #[test]
+    fn test_verdict() {
+        let expected = Solution::from(42u64);
+        assert_eq!(verdict(&Solution::from(42i32), Some(&expected)), " (correct)");
+        assert_eq!(verdict(&Solution::from(50u8), Some(&expected)), " (wrong, too high: expected 42)");
+        assert_eq!(verdict(&Solution::from(-1i64), Some(&expected)), " (wrong, too low: expected 42)");
+        assert_eq!(verdict(&Solution::from("ABC"), Some(&expected)), " (wrong: expected 42)");
+        assert_eq!(verdict(&Solution::Unsolved, Some(&expected)), "");
+        assert_eq!(verdict(&expected, None), "");
+
+        let picture = Solution::Multiline("#.#\n.#.".to_owned());
+        assert_eq!(verdict(&Solution::Multiline("#.#\n.#.\n".to_owned()), Some(&picture)), " (correct)");
+        assert_eq!(verdict(&Solution::Multiline("#.#\n##.".to_owned()), Some(&picture)), " (wrong)");
+    }
+}